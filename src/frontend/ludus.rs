@@ -0,0 +1,663 @@
+use super::narration::narrate;
+use super::{autosave, enemy_name, slot_label};
+use crate::models::character::Attribute;
+use crate::models::combat::{FightAction, MAX_EXCITEMENT};
+use crate::models::command::Command;
+use crate::models::event::Event;
+use crate::models::game::{Ending, Game, Phase};
+use crate::models::items::item::{Equipable, Slot};
+use crate::models::rebellion::{Stage, RECRUITS_NEEDED};
+use crate::models::save::{Saves, SAVE_SLOTS};
+use crate::models::training::{Regimen, DOCTORE_PRICE};
+use crate::models::utils::{meter, Table};
+use crossterm::event::{self, Event as TermEvent, KeyCode, KeyEventKind};
+use crossterm::execute;
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Row};
+use std::io::{self, stdout, Stdout};
+
+// Older messages are dropped from the log
+const LOG_LIMIT: usize = 200;
+// Games listed in the ludus so the player can plan their training
+const UPCOMING_GAMES: usize = 4;
+
+/// Why the player left the ludus screen.
+#[derive(Clone, Copy)]
+pub enum Exit {
+    MainMenu,
+    GameOver(Ending),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Menu {
+    Ludus,
+    Training,
+    Inventory,
+    Equip(Slot),
+    Store,
+    Buy(Slot),
+    Blacksmith,
+    Save,
+    ConfirmOverwrite(usize),
+    SaveName(usize),
+    Fight,
+    Mercy,
+    Verdict,
+    Spoils,
+    GameOver(Ending),
+}
+
+#[derive(Clone)]
+enum Action {
+    Run(Command),
+    Open(Menu),
+    SaveTo(usize),
+    Back,
+    Leave(Exit),
+}
+
+/// Full-screen ludus: status panel on top, menu on the left and
+/// details plus a message log on the right.
+pub struct Ludus<'a> {
+    game: &'a mut Game,
+    saves: &'a Saves,
+    menu: Menu,
+    selected: usize,
+    input: String,
+    log: Vec<String>,
+}
+
+impl<'a> Ludus<'a> {
+    pub fn new(game: &'a mut Game, saves: &'a Saves, log: Vec<String>) -> Self {
+        Ludus {
+            game,
+            saves,
+            menu: Menu::Ludus,
+            selected: 0,
+            input: String::new(),
+            log,
+        }
+    }
+
+    /// Takes over the terminal until the player leaves the ludus.
+    pub fn run(&mut self) -> io::Result<Exit> {
+        enable_raw_mode()?;
+        execute!(stdout(), EnterAlternateScreen)?;
+        let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
+
+        let result = self.event_loop(&mut terminal);
+
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+        terminal.show_cursor()?;
+        result
+    }
+
+    fn event_loop(
+        &mut self,
+        terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    ) -> io::Result<Exit> {
+        loop {
+            self.sync_menu();
+            terminal.draw(|frame| self.draw(frame))?;
+
+            let TermEvent::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+
+            if let Menu::SaveName(slot) = self.menu {
+                match key.code {
+                    KeyCode::Char(c) => self.input.push(c),
+                    KeyCode::Backspace => {
+                        self.input.pop();
+                    }
+                    KeyCode::Enter => self.save(slot),
+                    KeyCode::Esc => self.open(Menu::Save),
+                    _ => {}
+                }
+                continue;
+            }
+
+            let entries = self.entries();
+            match key.code {
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.selected = self.selected.checked_sub(1).unwrap_or(entries.len() - 1);
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.selected = (self.selected + 1) % entries.len();
+                }
+                KeyCode::Esc => self.back(),
+                KeyCode::Enter => {
+                    let (_, action) = entries[self.selected].clone();
+                    if let Some(exit) = self.perform(action) {
+                        return Ok(exit);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Fights and their aftermath take over the menu whatever the player was doing.
+    fn sync_menu(&mut self) {
+        let menu = match self.game.phase() {
+            Phase::Fighting(_) => Menu::Fight,
+            Phase::Defeated(_) => Menu::Mercy,
+            Phase::Verdict { .. } => Menu::Verdict,
+            Phase::Spoils(_) => Menu::Spoils,
+            Phase::Over(ending) => Menu::GameOver(*ending),
+            Phase::Ludus
+                if matches!(
+                    self.menu,
+                    Menu::Fight | Menu::Mercy | Menu::Verdict | Menu::Spoils
+                ) =>
+            {
+                Menu::Ludus
+            }
+            Phase::Ludus => self.menu,
+        };
+        if menu != self.menu {
+            self.open(menu);
+        }
+    }
+
+    fn open(&mut self, menu: Menu) {
+        self.menu = menu;
+        self.selected = 0;
+    }
+
+    fn back(&mut self) {
+        let parent = match self.menu {
+            Menu::Equip(_) => Menu::Inventory,
+            Menu::Buy(_) => Menu::Store,
+            Menu::ConfirmOverwrite(_) | Menu::SaveName(_) => Menu::Save,
+            Menu::Fight | Menu::Mercy | Menu::Verdict | Menu::Spoils | Menu::GameOver(_) => {
+                self.menu
+            }
+            _ => Menu::Ludus,
+        };
+        self.open(parent);
+    }
+
+    fn perform(&mut self, action: Action) -> Option<Exit> {
+        match action {
+            Action::Run(command) => self.execute(command),
+            Action::Open(menu) => self.open(menu),
+            Action::SaveTo(slot) => {
+                self.input = format!(
+                    "{} week {}",
+                    self.game.player().name,
+                    self.game.current_week()
+                );
+                self.open(Menu::SaveName(slot));
+            }
+            Action::Back => self.back(),
+            Action::Leave(exit) => return Some(exit),
+        }
+        None
+    }
+
+    fn execute(&mut self, command: Command) {
+        let enemy_name = enemy_name(self.game);
+        let events = self.game.execute(command);
+        for event in &events {
+            self.log.extend(narrate(event, &enemy_name));
+        }
+        if autosave(self.saves, self.game, &events).is_err() {
+            self.log.push("Autosave failed.".to_string());
+        }
+        if events.iter().any(|event| {
+            matches!(
+                event,
+                Event::Purchased { .. } | Event::Equipped { .. } | Event::Trained { .. }
+            )
+        }) {
+            self.back();
+        }
+        let overflow = self.log.len().saturating_sub(LOG_LIMIT);
+        self.log.drain(..overflow);
+    }
+
+    fn save(&mut self, slot: usize) {
+        let name = std::mem::take(&mut self.input);
+        match self.saves.save(slot, name, self.game) {
+            Ok(()) => self.log.push("Game saved.".to_string()),
+            Err(error) => self.log.push(format!("Failed to save game: {}", error)),
+        }
+        self.open(Menu::Ludus);
+    }
+
+    fn title(&self) -> &'static str {
+        match self.menu {
+            Menu::Ludus => "Ludus",
+            Menu::Training => "Training",
+            Menu::Inventory | Menu::Equip(_) => "Inventory",
+            Menu::Store | Menu::Buy(_) => "Store",
+            Menu::Blacksmith => "Blacksmith",
+            Menu::Save | Menu::ConfirmOverwrite(_) | Menu::SaveName(_) => "Save Game",
+            Menu::Fight => "Choose your action",
+            Menu::Mercy => "Beg For Mercy?",
+            Menu::Verdict => "Spare Your Opponent?",
+            Menu::Spoils => "Spoils of Victory",
+            Menu::GameOver(Ending::Freedom) => "Free at Last",
+            Menu::GameOver(Ending::Escape) => "Escaped",
+            Menu::GameOver(Ending::Death) => "Game Over",
+        }
+    }
+
+    fn entries(&self) -> Vec<(String, Action)> {
+        let entry = |label: &str, action: Action| (label.to_string(), action);
+
+        let mut entries = match self.menu {
+            Menu::Ludus if self.game.is_fight_week() => vec![
+                entry("Fight", Action::Run(Command::StartFight)),
+                entry("Skip fight", Action::Run(Command::SkipFight)),
+                entry("Inventory", Action::Open(Menu::Inventory)),
+                entry("Store", Action::Open(Menu::Store)),
+                entry("Blacksmith", Action::Open(Menu::Blacksmith)),
+                entry("Save Game", Action::Open(Menu::Save)),
+                entry("To Main Menu", Action::Leave(Exit::MainMenu)),
+            ],
+            Menu::Ludus => {
+                let mut entries = vec![
+                    entry("Train", Action::Open(Menu::Training)),
+                    entry("Rest", Action::Run(Command::Rest)),
+                    entry("Inventory", Action::Open(Menu::Inventory)),
+                    entry("Store", Action::Open(Menu::Store)),
+                    entry("Blacksmith", Action::Open(Menu::Blacksmith)),
+                    entry("Buy Freedom", Action::Run(Command::BuyFreedom)),
+                ];
+                let rebellion = self.game.rebellion();
+                let plot = match rebellion.stage {
+                    Stage::Dormant => None,
+                    Stage::Recruiting => Some((
+                        format!(
+                            "Recruit rebels ({}/{}, suspicion {}%)",
+                            rebellion.recruits, RECRUITS_NEEDED, rebellion.suspicion
+                        ),
+                        Command::Recruit,
+                    )),
+                    Stage::Preparing => Some((
+                        format!(
+                            "Prepare the revolt ({}%, suspicion {}%)",
+                            rebellion.preparation, rebellion.suspicion
+                        ),
+                        Command::PrepareRevolt,
+                    )),
+                    Stage::Ready => Some(("Revolt!".to_string(), Command::Revolt)),
+                };
+                if let Some((label, command)) = plot {
+                    entries.push((label, Action::Run(command)));
+                }
+                entries.push(entry("Save Game", Action::Open(Menu::Save)));
+                entries.push(entry("To Main Menu", Action::Leave(Exit::MainMenu)));
+                entries
+            }
+            Menu::Training => [false, true]
+                .into_iter()
+                .flat_map(|doctore| {
+                    Attribute::ALL.into_iter().map(move |attribute| {
+                        let regimen = Regimen { attribute, doctore };
+                        let label = if doctore {
+                            format!("{} ({} coins)", regimen, DOCTORE_PRICE)
+                        } else {
+                            regimen.to_string()
+                        };
+                        (label, Action::Run(Command::Train(regimen)))
+                    })
+                })
+                .collect(),
+            Menu::Inventory => Slot::ALL
+                .iter()
+                .map(|slot| entry(slot.category(), Action::Open(Menu::Equip(*slot))))
+                .collect(),
+            Menu::Store => Slot::ALL
+                .iter()
+                .map(|slot| entry(slot.category(), Action::Open(Menu::Buy(*slot))))
+                .collect(),
+            Menu::Equip(slot) => self
+                .game
+                .player()
+                .inventory
+                .items(slot)
+                .map(|(id, item)| entry(item.name(), Action::Run(Command::Equip(id))))
+                .collect(),
+            Menu::Buy(slot) => self
+                .game
+                .store()
+                .items
+                .items(slot)
+                .map(|(id, item)| entry(item.name(), Action::Run(Command::Buy(id))))
+                .collect(),
+            Menu::Blacksmith => {
+                let equipment = &self.game.player().equipment;
+                Slot::ALL
+                    .into_iter()
+                    .filter_map(|slot| equipment.get(slot).map(|item| (slot, item)))
+                    .map(|(slot, item)| {
+                        let label = format!(
+                            "Repair {} ({} coins)",
+                            item.name(),
+                            self.game.store().repair_price(item)
+                        );
+                        (label, Action::Run(Command::Repair(slot)))
+                    })
+                    .collect()
+            }
+            Menu::Save => (1..=SAVE_SLOTS)
+                .map(|slot| {
+                    let label =
+                        slot_label(slot, self.saves.metadata(slot), self.saves.is_used(slot));
+                    let action = if self.saves.is_used(slot) {
+                        Action::Open(Menu::ConfirmOverwrite(slot))
+                    } else {
+                        Action::SaveTo(slot)
+                    };
+                    (label, action)
+                })
+                .collect(),
+            Menu::ConfirmOverwrite(slot) => vec![
+                entry("Overwrite", Action::SaveTo(slot)),
+                entry("Cancel", Action::Back),
+            ],
+            Menu::SaveName(_) => Vec::new(),
+            Menu::Fight => vec![
+                entry("Attack", Action::Run(Command::Fight(FightAction::Attack))),
+                entry("Block", Action::Run(Command::Fight(FightAction::Block))),
+                entry("Wait", Action::Run(Command::Fight(FightAction::Wait))),
+            ],
+            Menu::Mercy => vec![
+                entry("Yes", Action::Run(Command::BegForMercy)),
+                entry("No", Action::Run(Command::RefuseMercy)),
+            ],
+            Menu::Verdict => vec![
+                entry("Spare", Action::Run(Command::SpareEnemy)),
+                entry("Kill", Action::Run(Command::KillEnemy)),
+            ],
+            Menu::Spoils => {
+                let mut entries: Vec<(String, Action)> = match self.game.phase() {
+                    Phase::Spoils(spoils) => spoils
+                        .items
+                        .iter()
+                        .enumerate()
+                        .map(|(index, item)| {
+                            let label = format!("Claim {} ({})", item.name(), item.rarity());
+                            (label, Action::Run(Command::Claim(index)))
+                        })
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                entries.push(entry(
+                    "Leave the rest to the lanista",
+                    Action::Run(Command::LeaveSpoils),
+                ));
+                entries
+            }
+            Menu::GameOver(ending) => {
+                vec![entry("Continue", Action::Leave(Exit::GameOver(ending)))]
+            }
+        };
+
+        if !matches!(
+            self.menu,
+            Menu::Ludus
+                | Menu::Fight
+                | Menu::Mercy
+                | Menu::Verdict
+                | Menu::Spoils
+                | Menu::GameOver(_)
+                | Menu::ConfirmOverwrite(_)
+        ) {
+            entries.push(entry("Back", Action::Back));
+        }
+        entries
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(6), Constraint::Min(0)])
+            .split(frame.size());
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+            .split(rows[1]);
+        let right = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(columns[1]);
+
+        self.draw_status(frame, rows[0]);
+        if let Menu::Ludus | Menu::Training = self.menu {
+            // Title row and borders around the upcoming games
+            let left = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Min(0),
+                    Constraint::Length(UPCOMING_GAMES as u16 + 3),
+                ])
+                .split(columns[0]);
+            self.draw_menu(frame, left[0]);
+            self.draw_calendar(frame, left[1]);
+        } else {
+            self.draw_menu(frame, columns[0]);
+        }
+        self.draw_details(frame, right[0]);
+        self.draw_log(frame, right[1]);
+    }
+
+    fn draw_status(&self, frame: &mut Frame, area: Rect) {
+        let player = self.game.player();
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", player.name));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let lines = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+
+        frame.render_widget(Paragraph::new(player.health_bar()), lines[0]);
+
+        let calendar = self.game.calendar();
+        let week = match calendar.games() {
+            Some(games) => format!("{} ({}) - {}", calendar, calendar.season(), games),
+            None => format!("{} ({})", calendar, calendar.season()),
+        };
+        let summary = format!(
+            "💰 {}   🏆 {} ({})   ⚡ {}   ⚔️  {} victories   {}   Seed {}",
+            player.money,
+            player.fame_level,
+            player.fame,
+            player.energy,
+            player.victories,
+            week,
+            self.game.seed()
+        );
+        frame.render_widget(Paragraph::new(summary), lines[1]);
+
+        if let Phase::Fighting(fight) | Phase::Defeated(fight) | Phase::Verdict { fight, .. } =
+            self.game.phase()
+        {
+            let enemy = &fight.enemy;
+            let opponent = if fight.escape {
+                format!("Opponent: {} ({})", enemy.name, enemy.behavior)
+            } else {
+                format!(
+                    "Opponent: {} ({})   Crowd: {} {}",
+                    enemy.name,
+                    enemy.behavior,
+                    fight.crowd_mood,
+                    meter(fight.excitement as i32, MAX_EXCITEMENT as i32)
+                )
+            };
+            frame.render_widget(Paragraph::new(opponent), lines[2]);
+            frame.render_widget(Paragraph::new(enemy.health_bar()), lines[3]);
+        }
+    }
+
+    fn draw_menu(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", self.title()));
+
+        if let Menu::SaveName(_) = self.menu {
+            let text = vec![
+                Line::from("Save name:"),
+                Line::from(format!("{}_", self.input)),
+                Line::from(""),
+                Line::from("Enter to save, Esc to cancel"),
+            ];
+            frame.render_widget(Paragraph::new(text).block(block), area);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .entries()
+            .into_iter()
+            .map(|(label, _)| ListItem::new(label))
+            .collect();
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(
+                Style::default()
+                    .add_modifier(Modifier::BOLD)
+                    .fg(Color::Yellow),
+            )
+            .highlight_symbol("> ");
+        let mut state = ListState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+
+    fn draw_calendar(&self, frame: &mut Frame, area: Rect) {
+        let calendar = self.game.calendar();
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", calendar.season()));
+        let table = calendar.upcoming_table(UPCOMING_GAMES);
+        frame.render_widget(table_widget(&table).block(block), area);
+    }
+
+    /// Whatever is relevant to the current menu: items on offer or the player's gear.
+    fn draw_details(&self, frame: &mut Frame, area: Rect) {
+        let player = self.game.player();
+        let (title, tables): (&str, Vec<Table>) = match self.menu {
+            Menu::Equip(slot) => ("Inventory", vec![player.inventory.table(slot)]),
+            Menu::Buy(slot) => (
+                "Store",
+                vec![self.game.store().table(slot, player.fame_level)],
+            ),
+            Menu::Blacksmith => (
+                "Blacksmith",
+                vec![self.game.store().repairs_table(&player.equipment)],
+            ),
+            Menu::Spoils => match self.game.phase() {
+                Phase::Spoils(spoils) => ("Spoils", vec![spoils.table()]),
+                _ => ("Spoils", Vec::new()),
+            },
+            _ => (
+                "Player Information",
+                vec![player.info_rows(), player.equipment.equipment_rows()],
+            ),
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", title));
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        // Title row, body rows and a blank line between tables
+        let heights: Vec<Constraint> = tables
+            .iter()
+            .map(|table| Constraint::Length(table.rows.len() as u16 + 2))
+            .collect();
+        let areas = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(heights)
+            .split(inner);
+        for (table, area) in tables.iter().zip(areas.iter()) {
+            frame.render_widget(table_widget(table), *area);
+        }
+    }
+
+    fn draw_log(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::default().borders(Borders::ALL).title(" Log ");
+        let inner = block.inner(area);
+        let width = inner.width.max(1) as usize;
+        let wrapped: Vec<String> = self.log.iter().flat_map(|line| wrap(line, width)).collect();
+        let visible = wrapped.len().saturating_sub(inner.height as usize);
+        let lines: Vec<Line> = wrapped[visible..]
+            .iter()
+            .map(|line| Line::from(line.as_str()))
+            .collect();
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+/// Columns are as wide as their widest cell.
+fn table_widget(table: &Table) -> ratatui::widgets::Table<'_> {
+    let mut widths: Vec<usize> = table
+        .titles
+        .iter()
+        .map(|title| Span::raw(title.as_str()).width())
+        .collect();
+    for row in &table.rows {
+        for (column, cell) in row.iter().enumerate() {
+            match widths.get_mut(column) {
+                Some(width) => *width = (*width).max(Span::raw(cell.as_str()).width()),
+                None => widths.push(Span::raw(cell.as_str()).width()),
+            }
+        }
+    }
+    let widths: Vec<Constraint> = widths
+        .into_iter()
+        .map(|width| Constraint::Length(width as u16))
+        .collect();
+
+    let header = Row::new(table.titles.iter().map(String::as_str))
+        .style(Style::default().add_modifier(Modifier::BOLD));
+    let rows = table
+        .rows
+        .iter()
+        .map(|row| Row::new(row.iter().map(String::as_str)));
+    ratatui::widgets::Table::new(rows, widths)
+        .header(header)
+        .column_spacing(2)
+}
+
+/// Breaks a line into pieces no wider than `width`, preferring word boundaries.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in text.split(' ') {
+        let needed = current.chars().count() + word.chars().count() + 1;
+        if !current.is_empty() && needed > width {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+        while current.chars().count() > width {
+            let rest = current.chars().skip(width).collect();
+            lines.push(current.chars().take(width).collect());
+            current = rest;
+        }
+    }
+    lines.push(current);
+    lines
+}
@@ -0,0 +1,60 @@
+use crate::models::event::Event;
+use crate::models::game::{Game, Phase};
+use crate::models::save::{SaveMetadata, Saves, AUTOSAVE_SLOT};
+use std::io;
+
+pub mod ludus;
+pub mod narration;
+pub mod terminal;
+
+/// Autosaves when a new week has started, once the spoils of a won fight
+/// are settled and when the story ends. Fights always end the week, so this
+/// covers both week boundaries and finished fights.
+pub fn autosave(saves: &Saves, game: &Game, events: &[Event]) -> io::Result<()> {
+    if events.iter().any(|event| {
+        matches!(
+            event,
+            Event::WeekAdvanced { .. }
+                | Event::SpoilsLeft { .. }
+                | Event::Killed { .. }
+                | Event::RevoltCrushed
+                | Event::Escaped
+                | Event::FreedomBought
+        )
+    }) {
+        saves.autosave(game)?;
+    }
+    Ok(())
+}
+
+/// Name of the current opponent, needed to narrate fight events.
+pub fn enemy_name(game: &Game) -> String {
+    match game.phase() {
+        Phase::Fighting(fight) | Phase::Defeated(fight) | Phase::Verdict { fight, .. } => {
+            fight.enemy.name.clone()
+        }
+        _ => String::new(),
+    }
+}
+
+pub fn slot_label(slot: usize, metadata: Option<SaveMetadata>, used: bool) -> String {
+    let title = if slot == AUTOSAVE_SLOT {
+        "Autosave".to_string()
+    } else {
+        format!("Slot {}", slot)
+    };
+    match metadata {
+        Some(metadata) => format!(
+            "{}: {} - {}, week {}, {} victories, {} coins ({})",
+            title,
+            metadata.name,
+            metadata.player_name,
+            metadata.week,
+            metadata.victories,
+            metadata.money,
+            metadata.saved_at()
+        ),
+        None if used => format!("{}: <unreadable save>", title),
+        None => format!("{}: <empty>", title),
+    }
+}
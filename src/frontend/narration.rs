@@ -0,0 +1,371 @@
+use crate::models::calendar::Season;
+use crate::models::combat::{FightAction, Strike};
+use crate::models::crowd::CrowdMood;
+use crate::models::event::Event;
+use crate::models::rebellion::PREPARATION_NEEDED;
+
+/// Turns an engine event into the lines of text shown to the player.
+pub fn narrate(event: &Event, enemy_name: &str) -> Vec<String> {
+    match event {
+        Event::WeekAdvanced { date, games } => match games {
+            Some(games) if games.is_festival() => vec![format!(
+                "{} begins. The {} are held this week, with richer purses!",
+                date, games
+            )],
+            Some(_) => vec![format!("{} begins. The games are coming!", date)],
+            None => vec![format!("{} begins.", date)],
+        },
+        Event::SeasonChanged { season } => vec![match season {
+            Season::Winter => {
+                "Winter comes. The arena stays closed until spring, but for the Saturnalia."
+                    .to_string()
+            }
+            Season::Spring => "Spring returns, and with it the games.".to_string(),
+            _ => format!("{} arrives.", season),
+        }],
+        Event::Trained {
+            regimen,
+            paid,
+            before,
+            after,
+            energy_before,
+            energy_after,
+        } => {
+            let mut lines = vec![format!("You spend the week on {}.", regimen.to_string().to_lowercase())];
+            if *paid > 0 {
+                lines.push(format!("The doctore takes {} coins for his time.", paid));
+            }
+            if after > before {
+                lines.push(format!(
+                    "Training report: {} {} -> {}, energy {} -> {}.",
+                    regimen.attribute, before, after, energy_before, energy_after
+                ));
+            } else {
+                lines.push(format!(
+                    "Training report: no progress, {} stays at {}, energy {} -> {}.",
+                    regimen.attribute, before, energy_before, energy_after
+                ));
+            }
+            lines
+        }
+        Event::CannotTrainInjured => {
+            vec!["You can't train because of your injury... Try resting first.".to_string()]
+        }
+        Event::TooTiredToTrain { energy } => vec![format!(
+            "You are too exhausted to train (⚡ {}). Rest first.",
+            energy
+        )],
+        Event::Rested {
+            healed,
+            health,
+            max_health,
+            energy,
+        } => vec![format!(
+            "You are resting. Restored {} health ({}/{}), energy is back to {}.",
+            healed, health, max_health, energy
+        )],
+        Event::Purchased { name, .. } => vec![format!("Purchased: {}", name)],
+        Event::NotEnoughMoney { name, price, money } => vec![
+            format!("Can not buy: {}", name),
+            format!("Not enought money: Required {}  You have {}", price, money),
+        ],
+        Event::Equipped { name } => vec![format!("Equipping: {}", name)],
+        Event::CannotWieldInjured { name } => vec![
+            format!("Can not equip: {}", name),
+            "Your broken arm can't hold a two-handed weapon.".to_string(),
+        ],
+        Event::StrengthTooLow {
+            name,
+            required,
+            current,
+        } => vec![
+            format!("Can not equip: {}", name),
+            format!(
+                "Strength Required: {} Current Strength {}",
+                required, current
+            ),
+        ],
+        Event::Repaired { name, price } => vec![format!(
+            "The blacksmith mends your {} for {} coins. It is as good as new.",
+            name, price
+        )],
+        Event::NothingToRepair { name } => {
+            vec![format!("Your {} doesn't need any repairs.", name)]
+        }
+        Event::CannotAffordRepair { name, price, money } => vec![
+            format!("Can not repair: {}", name),
+            format!("Not enought money: Required {}  You have {}", price, money),
+        ],
+        Event::ItemBroken { name } => vec![format!("Your {} breaks!", name)],
+        Event::NoSuchItem => vec!["There is no such item.".to_string()],
+        Event::NotFightWeek => vec!["There are no games this week.".to_string()],
+        Event::InjuredCannotFight => {
+            vec!["You are injured and can't fight this week.".to_string()]
+        }
+        Event::NotReadyToFight => {
+            vec!["You are not ready to fight. Your health is to low.".to_string()]
+        }
+        Event::FightSkipped { .. } => vec![
+            "You choose to skip this fight!".to_string(),
+            "Your lanista is not happy...".to_string(),
+            "Your are losing fame.".to_string(),
+        ],
+        Event::FightStarted {
+            enemy,
+            behavior,
+            mood,
+            games,
+        } => vec![
+            format!("You walk into the sand for the {}.", games),
+            format!(
+                "Your opponent today is {}, known as a {} fighter.",
+                enemy,
+                behavior.to_string().to_lowercase()
+            ),
+            match mood {
+                CrowdMood::Bloodthirsty => "The crowd is bloodthirsty today, hungry for a kill.",
+                CrowdMood::Restless => "The crowd is restless, waiting to be entertained.",
+                CrowdMood::Generous => "The crowd is in a generous mood today.",
+            }
+            .to_string(),
+        ],
+        Event::PlayerAction(action) => match action {
+            FightAction::Attack => vec!["Attacking...".to_string()],
+            FightAction::Block => vec!["Blocking...".to_string()],
+            FightAction::Wait => vec!["Waiting...".to_string()],
+        },
+        Event::PlayerStrike {
+            strike,
+            enemy_action,
+        } => match strike {
+            Strike::Evaded => vec![format!("{} sidesteps your attack.", enemy_name)],
+            Strike::Hit {
+                damage,
+                blocked,
+                location,
+            } => {
+                if *enemy_action == FightAction::Block && *blocked > 0 {
+                    vec![format!(
+                        "{} blocks, but you hit the {} for {} damage.",
+                        enemy_name, location, damage
+                    )]
+                } else {
+                    vec![format!(
+                        "You hit {} in the {} for {} damage.",
+                        enemy_name, location, damage
+                    )]
+                }
+            }
+        },
+        Event::EnemyAction(action) => match action {
+            FightAction::Attack => vec![format!("{} attacks!", enemy_name)],
+            FightAction::Block => vec![format!("{} raises their guard.", enemy_name)],
+            FightAction::Wait => vec![format!(
+                "{} circles you, catching their breath.",
+                enemy_name
+            )],
+        },
+        Event::EnemyStrike(strike) => match strike {
+            Strike::Evaded => vec!["You evade the blow.".to_string()],
+            Strike::Hit {
+                damage,
+                blocked,
+                location,
+            } if *blocked > 0 => vec![format!(
+                "You take {} damage to the {}, {} absorbed.",
+                damage, location, blocked
+            )],
+            Strike::Hit {
+                damage, location, ..
+            } => vec![format!("You take {} damage to the {}.", damage, location)],
+        },
+        Event::Victory {
+            purse,
+            lanista_share,
+            crowd_bonus,
+            festival_bonus,
+            first,
+        } => {
+            let mut lines = vec![
+                "Your decisive blow having vanquished your formidable enemy".to_string(),
+                "The crowd erupts in cheers, celebrating your triumph  as you emerge as the undisputed champion of the arena".to_string(),
+                format!(
+                    "You receive a purse of {} coins, {} of which go to your lanista.",
+                    purse, lanista_share
+                ),
+            ];
+            if *crowd_bonus > 0 {
+                lines.push(format!(
+                    "The thrilled crowd throws another {} coins into the sand.",
+                    crowd_bonus
+                ));
+            }
+            if *festival_bonus > 0 {
+                lines.push(format!(
+                    "The sponsors of the festival add {} coins.",
+                    festival_bonus
+                ));
+            }
+            if *first {
+                lines.extend(
+                    [
+                        "You are led out of the arena, not as a mere prisoner of war or a slave bound by chains, but as a warrior who has proven his mettle in the heat of combat.",
+                        "The man in a silk cloth and two body guards approach you...",
+                        "",
+                        "[LANISTA] - You fought well today, beyond what was expected for a first fight.  Your victory is just the start.",
+                        "He pauses, ensuring his words sink in before continuing.",
+                        "[LANISTA] - Train hard, fight harder. Remember, you're here because I chose you—I see the gladiator in you. Your past is irrelevant; your future in the arena is what matters now.",
+                        "The lanista's gaze hardens",
+                        "[LANISTA] - I see in you a fighter worth the investment - prove me right, fight well and you will be rewarded.",
+                        "Lanista leaves...Two of his bodyguards excort you to the Ludus.",
+                    ]
+                    .map(String::from),
+                );
+            }
+            lines
+        }
+        Event::Defeated => vec![format!("{} strikes you down.", enemy_name)],
+        Event::Injured { injury } => vec![format!(
+            "You are hurt in the {}: {}.",
+            injury.location, injury
+        )],
+        Event::InjuryHealed { injury } => vec![format!(
+            "Your {} has healed.",
+            injury.kind.to_string().to_lowercase()
+        )],
+        Event::EnemyDefeated {
+            crowd_wants_mercy: true,
+        } => vec![
+            format!("{} falls to the sand and raises a finger, begging for mercy.", enemy_name),
+            "The crowd waves their cloths and shouts \"Mitte!\" - they want the fallen spared.".to_string(),
+        ],
+        Event::EnemyDefeated {
+            crowd_wants_mercy: false,
+        } => vec![
+            format!("{} falls to the sand and raises a finger, begging for mercy.", enemy_name),
+            "The crowd turns their thumbs and shouts \"Iugula!\" - they want blood.".to_string(),
+        ],
+        Event::EnemySpared { obeyed_crowd: true } => vec![
+            format!("You lower your sword and let {} live. The stands cheer your clemency.", enemy_name),
+            "The other gladiators will remember that you spared one of their own.".to_string(),
+        ],
+        Event::EnemySpared {
+            obeyed_crowd: false,
+        } => vec![
+            format!("You lower your sword and let {} live, against the wish of the crowd.", enemy_name),
+            "The stands jeer and your lanista scowls at the lost spectacle, but the other gladiators will remember your clemency.".to_string(),
+        ],
+        Event::EnemyKilled { obeyed_crowd: true } => vec![
+            format!("You give the crowd the blood they demand and end {}.", enemy_name),
+            "Your lanista smiles, but the other gladiators look away.".to_string(),
+        ],
+        Event::EnemyKilled {
+            obeyed_crowd: false,
+        } => vec![
+            format!("You strike down {} while the crowd begs for mercy.", enemy_name),
+            "Boos rain down from the stands, your lanista is furious and the other gladiators will not forget it.".to_string(),
+        ],
+        Event::SpoilsOffered { claims } => vec![match claims {
+            0 => "Your opponent had nothing worth taking.".to_string(),
+            1 => "You may claim one piece of your opponent's gear.".to_string(),
+            claims => format!("You may claim {} pieces of your opponent's gear.", claims),
+        }],
+        Event::SpoilClaimed { name } => vec![format!("You claim the {}.", name)],
+        Event::SpoilsLeft { sold: 0 } => Vec::new(),
+        Event::SpoilsLeft { sold: 1 } => {
+            vec!["Your lanista sells the last piece at the market.".to_string()]
+        }
+        Event::SpoilsLeft { sold } => vec![format!(
+            "Your lanista sells the remaining {} pieces at the market.",
+            sold
+        )],
+        Event::MercyGranted => vec![
+            "You raising your trembling hands in a desperate plea for mercy. Your eyes, filled with a mix of fear and resignation, scan the sea of faces in the crowded stands, searching for a hint of compassion. ".to_string(),
+            "To your surprise, amidst the tumult, a wave of compassion seems to sweep over the spectators, and they signal for mercy, sparing your life. With a heavy heart and a sense of shame, you slowly rise and exit the Colosseum, alive but forever marked by the day the crowd chose to let you live.".to_string(),
+        ],
+        Event::Killed { begged: true } => vec![
+            "You raising your trembling hands in a desperate plea for mercy. Your eyes, filled with a mix of fear and resignation, scan the sea of faces in the crowded stands, searching for a hint of compassion. ".to_string(),
+            "Your gaze, filled with fear and hope, meets a sea of unyielding faces, their chants for violence drowning out your plea.".to_string(),
+            "In that heart-wrenching moment, as the crowd's rejection seals your fate, you feel the cold, sharp sting of your enemy's weapon, ending your desperate fight for survival.".to_string(),
+        ],
+        Event::Killed { begged: false } => vec![
+            "Defeated yet defiant, you lie wounded in the Colosseum's arena, refusing to plead for mercy. Your pride remains unbroken, even in the face of imminent death.".to_string(),
+            "You feel the cold, sharp sting of your enemy's weapon and everything turns black...".to_string(),
+        ],
+        Event::RebellionUnlocked => vec![
+            "At night, in the cells, the other gladiators have started to whisper your name.".to_string(),
+            "A fighter like you could lead them out of here. In the quiet weeks between the games, you could start recruiting...".to_string(),
+        ],
+        Event::Recruited { recruits, needed } if recruits >= needed => vec![
+            "Another gladiator clasps your arm and swears to follow you.".to_string(),
+            format!("With {} sworn brothers, it is time to gather weapons and keys for the revolt.", recruits),
+        ],
+        Event::Recruited { recruits, needed } => vec![format!(
+            "Another gladiator clasps your arm and swears to follow you. {} of {} recruits.",
+            recruits, needed
+        )],
+        Event::RecruitRefused => vec![
+            "The gladiator you approach turns away without a word. You can only hope they keep quiet.".to_string(),
+        ],
+        Event::RevoltPrepared {
+            progress,
+            preparation,
+        } => {
+            let mut lines = vec![format!(
+                "Blades are hidden in the straw and a guard is bribed. Preparations {}% (+{}).",
+                preparation, progress
+            )];
+            if *preparation >= PREPARATION_NEEDED {
+                lines.push("Everything is ready. The next quiet night, you could strike.".to_string());
+            }
+            lines
+        }
+        Event::PlotDiscovered { recruits_lost } => vec![
+            "Torches in the cells at night. Someone talked, and the lanista knows of the plot.".to_string(),
+            format!(
+                "You are dragged into the yard and flogged in front of everyone, and {} of your recruits are sold off to the mines.",
+                recruits_lost
+            ),
+            "If you want out, you will have to start again.".to_string(),
+        ],
+        Event::RevoltStarted { enemy, recruits } => vec![
+            format!(
+                "On a moonless night you and {} gladiators break out of the cells with stolen blades.",
+                recruits
+            ),
+            "The guards fall back to the gate, where the last of them make their stand.".to_string(),
+            format!("{} steps forward to meet you.", enemy),
+        ],
+        Event::Escaped => vec![
+            format!("{} falls, and the gate swings open.", enemy_name),
+            "You lead the rebels out of the ludus and into the hills, free men and women by the strength of your own arms. Rome will speak your name for years to come...".to_string(),
+        ],
+        Event::RevoltCrushed => vec![
+            format!("{} stands over you as the revolt collapses around the gate.", enemy_name),
+            "The surviving rebels are crucified along the road to Capua, and you are not spared their fate...".to_string(),
+        ],
+        Event::FreedomBought => vec![
+            "As you approach the owner of the ludus, your heart races with a mix of hope and anxiety. In your hand is a pouch of coins, meticulously saved over the years, representing your earnest attempt to buy your own freedom.".to_string(),
+            "To your immense relief, the owner nods in acceptance, a greedy glint in his eye as he takes the hefty pouch of coins. Your eyes light up with disbelief and gratitude. With the shackles of servitude finally broken, you step out of the ludus, a free man, walking into a new life filled with endless possibilities...".to_string(),
+        ],
+        Event::FreedomRefused { .. } => vec![
+            "As you approach the owner of the ludus, your heart races with a mix of hope and anxiety. In your hand is a pouch of coins, meticulously saved over the years, representing your earnest attempt to buy your own freedom.".to_string(),
+            "With a deep breath, you present your offer, only to see a frown crease the owner's face. He weighs the coins with a dismissive glance and declares it insufficient, his words crushing your hopes like fragile leaves underfoot. You return to the confines of the ludus, and your dream of freedom slipping away like sand through your fingers.".to_string(),
+        ],
+        Event::FameChanged { change, fame } if *change > 0 => {
+            vec![format!("The crowd chants your name. Fame +{} ({}).", change, fame)]
+        }
+        Event::FameChanged { change, fame } => {
+            vec![format!("Your name is fading. Fame {} ({}).", change, fame)]
+        }
+        Event::FameLevelChanged {
+            level,
+            promoted: true,
+        } => vec![format!("Word of your deeds spreads. Your rank in the arena rises to {}!", level)],
+        Event::FameLevelChanged {
+            level,
+            promoted: false,
+        } => vec![format!("The crowd forgets you. Your rank in the arena falls to {}.", level)],
+        Event::CommandUnavailable => vec!["You can't do that right now.".to_string()],
+    }
+}
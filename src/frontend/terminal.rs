@@ -0,0 +1,324 @@
+use super::ludus::{Exit, Ludus};
+use super::narration::narrate;
+use super::{enemy_name, slot_label};
+use crate::models::character::{Attribute, Character, Origin, ATTRIBUTE_POINTS};
+use crate::models::command::Command;
+use crate::models::game::{Ending, Game};
+use crate::models::save::{load_game, SaveError, Saves, AUTOSAVE_SLOT, SAVE_SLOTS};
+use crate::models::utils::{clear_screen, print_line, print_logo, slow_type};
+use console::Term;
+use dialoguer::theme::ColorfulTheme;
+use dialoguer::{Confirm, Input, Select};
+use std::path::PathBuf;
+use std::process;
+
+// Name of players started from the main menu
+const DEFAULT_PLAYER_NAME: &str = "Playername";
+
+/// Where a session begins, chosen on the command line.
+pub enum Start {
+    MainMenu,
+    NewGame(String),
+    LoadSlot(usize),
+    LoadFile(PathBuf),
+}
+
+enum Screen {
+    MainMenu,
+    InGame,
+    GameOver(Ending),
+    Exit,
+}
+
+/// Main menu, story and save management with dialoguer prompts and slowly typed
+/// narration; the game itself is played in the full-screen [`Ludus`].
+pub struct Terminal {
+    game: Game,
+    screen: Screen,
+    seed: Option<u64>,
+    saves: Saves,
+}
+
+impl Terminal {
+    /// `seed` fixes the randomness of every new game started from this session,
+    /// save slots are kept in `save_dir`.
+    pub fn new(seed: Option<u64>, save_dir: PathBuf) -> Self {
+        Terminal {
+            game: Game::new(&Character::new(DEFAULT_PLAYER_NAME.to_string())),
+            screen: Screen::MainMenu,
+            seed,
+            saves: Saves::new(save_dir),
+        }
+    }
+
+    pub fn main_loop(&mut self, start: Start) {
+        match start {
+            Start::MainMenu => {}
+            Start::NewGame(name) => {
+                let character = self.create_character(Some(name));
+                self.new_game(character);
+            }
+            Start::LoadSlot(slot) => {
+                let loaded = self.saves.load(slot);
+                self.start_loaded_game(loaded);
+            }
+            Start::LoadFile(path) => self.start_loaded_game(load_game(&path)),
+        }
+        loop {
+            match self.screen {
+                Screen::MainMenu => self.main_menu(),
+                Screen::InGame => self.ludus(Vec::new()),
+                Screen::GameOver(ending) => self.end_game(ending),
+                Screen::Exit => self.exit(),
+            }
+        }
+    }
+
+    fn exit(&mut self) {
+        // slow_type("Bye!");
+        process::exit(0);
+    }
+
+    fn get_selection(&self, promt: &str, options: &[String]) -> usize {
+        Select::with_theme(&ColorfulTheme::default())
+            .with_prompt(promt)
+            .default(0)
+            .items(options)
+            .interact()
+            .unwrap()
+    }
+
+    fn main_menu(&mut self) {
+        let term = Term::stdout();
+        term.clear_screen().unwrap();
+        print_line();
+        print_logo();
+        print_line();
+        let main_menu_options = vec![
+            "New Game".to_string(),
+            "Load Game".to_string(),
+            "Scores".to_string(),
+            "Exit".to_string(),
+        ];
+        let main_selection = self.get_selection("Main Menu", &main_menu_options);
+        match main_selection {
+            0 => {
+                let character = self.create_character(None);
+                self.new_game(character);
+            }
+            1 => self.load_game_menu(), // Call the function to handle loading a game
+            2 => slow_type("Showing high scores..."),
+            3 => self.screen = Screen::Exit,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Asks for the name only when it was not given on the command line.
+    fn create_character(&self, name: Option<String>) -> Character {
+        let name = name.unwrap_or_else(|| {
+            Input::with_theme(&ColorfulTheme::default())
+                .with_prompt("What is your name, gladiator?")
+                .default(DEFAULT_PLAYER_NAME.to_string())
+                .interact_text()
+                .unwrap()
+        });
+        let mut character = Character::new(name);
+
+        let origins: Vec<String> = Origin::ALL
+            .iter()
+            .map(|origin| format!("{} - {}", origin, origin.description()))
+            .collect();
+        character.origin =
+            Origin::ALL[self.get_selection("How did you end up in the ludus?", &origins)];
+
+        character.reset_points();
+        loop {
+            let mut options: Vec<String> = Attribute::ALL
+                .iter()
+                .map(|&attribute| format!("{}: {}", attribute, character.attribute(attribute)))
+                .collect();
+            options.push("Reset Points".to_string());
+            options.push("Done".to_string());
+            let prompt = format!(
+                "Distribute your points ({} of {} left)",
+                character.points_left(),
+                ATTRIBUTE_POINTS
+            );
+            let selection = self.get_selection(&prompt, &options);
+            if let Some(&attribute) = Attribute::ALL.get(selection) {
+                if !character.spend(attribute) {
+                    slow_type("You have no points left.");
+                }
+            } else if selection == Attribute::ALL.len() {
+                character.reset_points();
+            } else if character.points_left() == 0
+                || self.confirm("Leave the remaining points unspent?")
+            {
+                return character;
+            }
+        }
+    }
+
+    fn new_game(&mut self, character: Character) {
+        self.game = match self.seed {
+            Some(seed) => Game::with_seed(&character, seed),
+            None => Game::new(&character),
+        };
+        clear_screen();
+        slow_type("INTRODUCTION...");
+        slow_type(backstory(character.origin));
+        let text = "You found yourself in the arena...with a rusty sword in your hand and a terrifying enemy in front of you";
+        slow_type(text);
+        let events = self.game.execute(Command::StartFight);
+        let enemy_name = enemy_name(&self.game);
+        let log = events
+            .iter()
+            .flat_map(|event| narrate(event, &enemy_name))
+            .collect();
+        self.ludus(log);
+    }
+
+    /// Hands the terminal over to the full-screen ludus until the player leaves it.
+    fn ludus(&mut self, log: Vec<String>) {
+        let exit = Ludus::new(&mut self.game, &self.saves, log).run();
+        self.screen = match exit {
+            Ok(Exit::MainMenu) => Screen::MainMenu,
+            Ok(Exit::GameOver(ending)) => Screen::GameOver(ending),
+            Err(error) => {
+                println!("Terminal error: {}", error);
+                Screen::Exit
+            }
+        };
+    }
+
+    /// One menu entry per save slot.
+    fn slot_labels(&self) -> Vec<String> {
+        (1..=SAVE_SLOTS)
+            .map(|slot| {
+                let metadata = self.saves.metadata(slot);
+                slot_label(slot, metadata, self.saves.is_used(slot))
+            })
+            .collect()
+    }
+
+    fn load_game_menu(&mut self) {
+        let mut slots: Vec<usize> = (1..=SAVE_SLOTS).collect();
+        let mut save_options = self.slot_labels();
+        if self.saves.is_used(AUTOSAVE_SLOT) {
+            slots.insert(0, AUTOSAVE_SLOT);
+            let metadata = self.saves.metadata(AUTOSAVE_SLOT);
+            save_options.insert(0, slot_label(AUTOSAVE_SLOT, metadata, true));
+        }
+        save_options.push("Restore Backup".to_string());
+        save_options.push("Delete Save".to_string());
+        save_options.push("Back to Main Menu".to_string());
+
+        let save_selection = self.get_selection("Load Game", &save_options);
+
+        if let Some(&slot) = slots.get(save_selection) {
+            if !self.saves.is_used(slot) {
+                slow_type("This slot is empty.");
+                return;
+            }
+            slow_type("Loading...");
+            let loaded = self.saves.load(slot);
+            self.start_loaded_game(loaded);
+        } else if save_selection == slots.len() {
+            self.restore_backup_menu();
+        } else if save_selection == slots.len() + 1 {
+            self.delete_save_menu();
+        }
+    }
+
+    fn start_loaded_game(&mut self, loaded: Result<Game, SaveError>) {
+        match loaded {
+            Ok(game) => {
+                self.game = game;
+                self.screen = Screen::InGame;
+            }
+            Err(error) => {
+                slow_type("Failed to load game.");
+                slow_type(error.to_string().as_str());
+            }
+        }
+    }
+
+    fn restore_backup_menu(&mut self) {
+        let slots: Vec<usize> = std::iter::once(AUTOSAVE_SLOT)
+            .chain(1..=SAVE_SLOTS)
+            .filter(|&slot| self.saves.has_backup(slot))
+            .collect();
+        if slots.is_empty() {
+            slow_type("There are no backups to restore.");
+            return;
+        }
+
+        let mut options: Vec<String> = slots
+            .iter()
+            .map(|&slot| slot_label(slot, self.saves.backup_metadata(slot), true))
+            .collect();
+        options.push("Back".to_string());
+
+        let selection = self.get_selection("Restore Backup", &options);
+        let Some(&slot) = slots.get(selection) else {
+            return;
+        };
+        if self.confirm("Replace the current save with its backup?") {
+            let restored = self.saves.restore_backup(slot);
+            self.start_loaded_game(restored);
+        }
+    }
+
+    fn delete_save_menu(&mut self) {
+        let mut save_options = self.slot_labels();
+        save_options.push("Back".to_string());
+
+        let selection = self.get_selection("Delete Save", &save_options);
+        if selection == SAVE_SLOTS {
+            return;
+        }
+        let slot = selection + 1;
+        if !self.saves.is_used(slot) {
+            slow_type("This slot is empty.");
+            return;
+        }
+        if self.confirm(format!("Delete save in slot {}?", slot).as_str()) {
+            match self.saves.delete(slot) {
+                Ok(()) => slow_type("Save deleted."),
+                Err(_) => slow_type("Failed to delete save."),
+            }
+        }
+    }
+
+    fn confirm(&self, prompt: &str) -> bool {
+        Confirm::with_theme(&ColorfulTheme::default())
+            .with_prompt(prompt)
+            .default(false)
+            .interact()
+            .unwrap()
+    }
+
+    fn end_game(&mut self, ending: Ending) {
+        slow_type(" ");
+        slow_type(match ending {
+            Ending::Freedom => {
+                "FREE AT LAST. Your story as a gladiator ends here, bought and paid for."
+            }
+            Ending::Escape => {
+                "ESCAPED. Your story as a gladiator ends here, in the hills beyond Capua."
+            }
+            Ending::Death => "GAME OVER...",
+        });
+        slow_type(" ");
+        slow_type(" ");
+        self.screen = Screen::Exit;
+    }
+}
+
+fn backstory(origin: Origin) -> &'static str {
+    match origin {
+        Origin::PrisonerOfWar => "Your legion was crushed on the frontier. In chains, you were marched to Capua and sold to a lanista for a handful of coins.",
+        Origin::Debtor => "Your creditors lost their patience. What was left of your life was sold to a lanista, but a few coins stayed hidden in your tunic.",
+        Origin::Volunteer => "Tired of hunger and dreaming of glory, you signed the oath of the gladiator: to be burned, bound, beaten and killed by the sword.",
+    }
+}
@@ -1,10 +1,104 @@
+use clap::{Arg, ArgGroup, Command};
 use console::Term;
+use frontend::terminal::Start;
+use models::save::SAVE_SLOTS;
+use models::utils::{set_typing_delay, DEFAULT_TYPING_DELAY_MS};
+use std::path::PathBuf;
 
+mod frontend;
 mod models;
 
 fn main() {
+    let matches = Command::new("terminal_arena")
+        .about("A TUI text based game about fighting in the arena")
+        .arg(
+            Arg::new("name")
+                .long("name")
+                .takes_value(true)
+                .value_name("NAME")
+                .help(
+                    "Start a new game with a gladiator of this name, then pick an origin and stats",
+                ),
+        )
+        .arg(
+            Arg::new("load")
+                .long("load")
+                .takes_value(true)
+                .value_name("SLOT")
+                .validator(|value| match value.parse::<usize>() {
+                    Ok(slot) if slot <= SAVE_SLOTS => Ok(()),
+                    _ => Err(format!(
+                        "expected a slot from 0 (autosave) to {}",
+                        SAVE_SLOTS
+                    )),
+                })
+                .help("Load a save slot right away, 0 is the autosave"),
+        )
+        .arg(
+            Arg::new("load-file")
+                .long("load-file")
+                .takes_value(true)
+                .value_name("PATH")
+                .help("Load a save file right away"),
+        )
+        .group(ArgGroup::new("start").args(&["name", "load", "load-file"]))
+        .arg(
+            Arg::new("text-speed")
+                .long("text-speed")
+                .takes_value(true)
+                .value_name("MILLISECONDS")
+                .validator(|value| value.parse::<u64>())
+                .help("Delay between slowly typed characters"),
+        )
+        .arg(
+            Arg::new("no-slow-typing")
+                .long("no-slow-typing")
+                .conflicts_with("text-speed")
+                .help("Print story text at once"),
+        )
+        .arg(
+            Arg::new("seed")
+                .long("seed")
+                .takes_value(true)
+                .value_name("NUMBER")
+                .validator(|value| value.parse::<u64>())
+                .help("Seed for the random number generator of new games"),
+        )
+        .arg(
+            Arg::new("save-dir")
+                .long("save-dir")
+                .takes_value(true)
+                .value_name("DIR")
+                .default_value(".")
+                .help("Directory holding the save slots"),
+        )
+        .get_matches();
+
+    let seed = matches
+        .value_of("seed")
+        .map(|value| value.parse::<u64>().unwrap());
+    let save_dir = PathBuf::from(matches.value_of("save-dir").unwrap());
+    let typing_delay = if matches.is_present("no-slow-typing") {
+        0
+    } else {
+        matches
+            .value_of("text-speed")
+            .map_or(DEFAULT_TYPING_DELAY_MS, |value| value.parse().unwrap())
+    };
+    set_typing_delay(typing_delay);
+
+    let start = if let Some(name) = matches.value_of("name") {
+        Start::NewGame(name.to_string())
+    } else if let Some(slot) = matches.value_of("load") {
+        Start::LoadSlot(slot.parse().unwrap())
+    } else if let Some(path) = matches.value_of("load-file") {
+        Start::LoadFile(PathBuf::from(path))
+    } else {
+        Start::MainMenu
+    };
+
     let term = Term::stdout();
     term.clear_screen().unwrap();
-    let mut game = models::game::Game::new();
-    game.main_loop();
+    let mut terminal = frontend::terminal::Terminal::new(seed, save_dir);
+    terminal.main_loop(start);
 }
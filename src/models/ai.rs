@@ -0,0 +1,185 @@
+use super::combat::FightAction;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// Above this tiredness level anyone but a berserker starts catching their breath
+const TIRED_THRESHOLD: u8 = 70;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Behavior {
+    Aggressive,
+    Defensive,
+    Cautious,
+    Berserker,
+}
+
+impl fmt::Display for Behavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Behavior::Aggressive => write!(f, "Aggressive"),
+            Behavior::Defensive => write!(f, "Defensive"),
+            Behavior::Cautious => write!(f, "Cautious"),
+            Behavior::Berserker => write!(f, "Berserker"),
+        }
+    }
+}
+
+/// What an enemy knows about the fight when picking its next move.
+#[derive(Debug, Clone)]
+pub struct Situation {
+    pub own_health: f32,      // 0.0 - 1.0
+    pub opponent_health: f32, // 0.0 - 1.0
+    pub own_tiredness: u8,
+    pub opponent_tiredness: u8,
+}
+
+impl Behavior {
+    /// Relative weight of every action the profile may pick in this situation.
+    pub fn weights(&self, situation: &Situation) -> Vec<(FightAction, u32)> {
+        let (mut attack, mut block, mut wait): (u32, u32, u32) = match self {
+            Behavior::Aggressive if situation.own_health < 0.3 => (50, 40, 10),
+            Behavior::Aggressive => (70, 20, 10),
+            Behavior::Defensive if situation.opponent_health < 0.3 => (60, 30, 10),
+            Behavior::Defensive => (35, 50, 15),
+            Behavior::Cautious if situation.own_health < 0.5 => (25, 45, 30),
+            Behavior::Cautious => (40, 30, 30),
+            // A wounded berserker stops caring about anything but the kill
+            Behavior::Berserker if situation.own_health < 0.3 => (100, 0, 0),
+            Behavior::Berserker => (85, 5, 10),
+        };
+
+        if *self != Behavior::Berserker && situation.own_tiredness > TIRED_THRESHOLD {
+            wait += 40;
+        }
+        // An exhausted opponent is an invitation to press the attack
+        if situation.opponent_tiredness > TIRED_THRESHOLD {
+            attack += 20;
+            block = block.saturating_sub(10);
+        }
+
+        vec![
+            (FightAction::Attack, attack),
+            (FightAction::Block, block),
+            (FightAction::Wait, wait),
+        ]
+    }
+
+    pub fn choose_action<R: Rng>(&self, rng: &mut R, situation: &Situation) -> FightAction {
+        let weights = self.weights(situation);
+        let total: u32 = weights.iter().map(|(_, weight)| weight).sum();
+        let mut roll = rng.gen_range(0, total.max(1));
+        for (action, weight) in weights {
+            if roll < weight {
+                return action;
+            }
+            roll -= weight;
+        }
+        FightAction::Attack
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::rng::GameRng;
+
+    const FRESH: Situation = Situation {
+        own_health: 1.0,
+        opponent_health: 1.0,
+        own_tiredness: 0,
+        opponent_tiredness: 0,
+    };
+
+    fn weight(behavior: Behavior, situation: &Situation, action: FightAction) -> u32 {
+        behavior
+            .weights(situation)
+            .into_iter()
+            .find(|(candidate, _)| *candidate == action)
+            .map_or(0, |(_, weight)| weight)
+    }
+
+    #[test]
+    fn wounded_fighters_change_their_style() {
+        let wounded = Situation {
+            own_health: 0.2,
+            ..FRESH
+        };
+        // The aggressive back off, berserkers only attack
+        assert!(
+            weight(Behavior::Aggressive, &wounded, FightAction::Block)
+                > weight(Behavior::Aggressive, &FRESH, FightAction::Block)
+        );
+        assert_eq!(
+            Behavior::Berserker.weights(&wounded),
+            vec![
+                (FightAction::Attack, 100),
+                (FightAction::Block, 0),
+                (FightAction::Wait, 0),
+            ]
+        );
+        let mut rng = GameRng::new(1);
+        assert!((0..100)
+            .all(|_| Behavior::Berserker.choose_action(&mut rng, &wounded) == FightAction::Attack));
+    }
+
+    #[test]
+    fn tired_fighters_catch_their_breath_except_berserkers() {
+        let tired = Situation {
+            own_tiredness: TIRED_THRESHOLD + 1,
+            ..FRESH
+        };
+        for behavior in [
+            Behavior::Aggressive,
+            Behavior::Defensive,
+            Behavior::Cautious,
+        ] {
+            assert_eq!(
+                weight(behavior, &tired, FightAction::Wait),
+                weight(behavior, &FRESH, FightAction::Wait) + 40,
+                "{}",
+                behavior
+            );
+        }
+        assert_eq!(
+            Behavior::Berserker.weights(&tired),
+            Behavior::Berserker.weights(&FRESH)
+        );
+    }
+
+    #[test]
+    fn an_exhausted_opponent_draws_attacks() {
+        let opening = Situation {
+            opponent_tiredness: TIRED_THRESHOLD + 1,
+            ..FRESH
+        };
+        for behavior in [
+            Behavior::Aggressive,
+            Behavior::Defensive,
+            Behavior::Cautious,
+            Behavior::Berserker,
+        ] {
+            assert_eq!(
+                weight(behavior, &opening, FightAction::Attack),
+                weight(behavior, &FRESH, FightAction::Attack) + 20
+            );
+            assert_eq!(
+                weight(behavior, &opening, FightAction::Block),
+                weight(behavior, &FRESH, FightAction::Block).saturating_sub(10)
+            );
+        }
+    }
+
+    #[test]
+    fn actions_are_picked_in_proportion_to_their_weight() {
+        let mut rng = GameRng::new(1);
+        let picks: Vec<FightAction> = (0..1000)
+            .map(|_| Behavior::Defensive.choose_action(&mut rng, &FRESH))
+            .collect();
+        let count = |action| picks.iter().filter(|pick| **pick == action).count();
+        // 35 / 50 / 15 out of 100
+        assert!((250..450).contains(&count(FightAction::Attack)));
+        assert!((400..600).contains(&count(FightAction::Block)));
+        assert!((80..220).contains(&count(FightAction::Wait)));
+    }
+}
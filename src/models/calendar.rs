@@ -0,0 +1,252 @@
+use super::utils::Table;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+pub const WEEKS_PER_MONTH: u64 = 4;
+pub const WEEKS_PER_YEAR: u64 = WEEKS_PER_MONTH * 12;
+
+/// Months of the old Roman year, which began in Martius.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Month {
+    Martius,
+    Aprilis,
+    Maius,
+    Iunius,
+    Quintilis,
+    Sextilis,
+    September,
+    October,
+    November,
+    December,
+    Ianuarius,
+    Februarius,
+}
+
+impl fmt::Display for Month {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Month::Martius => write!(f, "Martius"),
+            Month::Aprilis => write!(f, "Aprilis"),
+            Month::Maius => write!(f, "Maius"),
+            Month::Iunius => write!(f, "Iunius"),
+            Month::Quintilis => write!(f, "Quintilis"),
+            Month::Sextilis => write!(f, "Sextilis"),
+            Month::September => write!(f, "September"),
+            Month::October => write!(f, "October"),
+            Month::November => write!(f, "November"),
+            Month::December => write!(f, "December"),
+            Month::Ianuarius => write!(f, "Ianuarius"),
+            Month::Februarius => write!(f, "Februarius"),
+        }
+    }
+}
+
+impl Month {
+    pub const ALL: [Month; 12] = [
+        Month::Martius,
+        Month::Aprilis,
+        Month::Maius,
+        Month::Iunius,
+        Month::Quintilis,
+        Month::Sextilis,
+        Month::September,
+        Month::October,
+        Month::November,
+        Month::December,
+        Month::Ianuarius,
+        Month::Februarius,
+    ];
+
+    pub fn season(&self) -> Season {
+        match self {
+            Month::Martius | Month::Aprilis | Month::Maius => Season::Spring,
+            Month::Iunius | Month::Quintilis | Month::Sextilis => Season::Summer,
+            Month::September | Month::October | Month::November => Season::Autumn,
+            Month::December | Month::Ianuarius | Month::Februarius => Season::Winter,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl fmt::Display for Season {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Season::Spring => write!(f, "Spring"),
+            Season::Summer => write!(f, "Summer"),
+            Season::Autumn => write!(f, "Autumn"),
+            Season::Winter => write!(f, "Winter"),
+        }
+    }
+}
+
+impl Season {
+    /// The arena stays closed in winter, festivals aside.
+    pub fn is_off_season(&self) -> bool {
+        *self == Season::Winter
+    }
+}
+
+/// Games held in the arena: the regular munera at the start of every month
+/// of the season, or one of the festivals that replace them.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Games {
+    Munera,
+    LudiMegalenses,
+    LudiApollinares,
+    LudiRomani,
+    LudiPlebeii,
+    Saturnalia,
+}
+
+impl fmt::Display for Games {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Games::Munera => write!(f, "Munera"),
+            Games::LudiMegalenses => write!(f, "Ludi Megalenses"),
+            Games::LudiApollinares => write!(f, "Ludi Apollinares"),
+            Games::LudiRomani => write!(f, "Ludi Romani"),
+            Games::LudiPlebeii => write!(f, "Ludi Plebeii"),
+            Games::Saturnalia => write!(f, "Saturnalia"),
+        }
+    }
+}
+
+impl Games {
+    pub const FESTIVALS: [Games; 5] = [
+        Games::LudiMegalenses,
+        Games::LudiApollinares,
+        Games::LudiRomani,
+        Games::LudiPlebeii,
+        Games::Saturnalia,
+    ];
+
+    pub fn is_festival(&self) -> bool {
+        *self != Games::Munera
+    }
+
+    /// Month and week of the month (from 1) of a festival.
+    fn date(&self) -> Option<(Month, u64)> {
+        match self {
+            Games::Munera => None,
+            Games::LudiMegalenses => Some((Month::Aprilis, 2)),
+            Games::LudiApollinares => Some((Month::Quintilis, 2)),
+            Games::LudiRomani => Some((Month::September, 3)),
+            Games::LudiPlebeii => Some((Month::November, 3)),
+            Games::Saturnalia => Some((Month::December, 3)),
+        }
+    }
+
+    /// Percentage the sponsors of a festival add to every purse.
+    pub fn purse_bonus(&self) -> i32 {
+        match self {
+            Games::Munera => 0,
+            Games::LudiRomani | Games::Saturnalia => 100,
+            _ => 50,
+        }
+    }
+}
+
+/// The date in the game, counted in weeks since the player arrived
+/// at the ludus in the first week of Martius.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Calendar {
+    week: u64,
+}
+
+impl fmt::Display for Calendar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Week {} of {}, year {}",
+            self.week_of_month(),
+            self.month(),
+            self.year()
+        )
+    }
+}
+
+impl Calendar {
+    pub fn new(week: u64) -> Self {
+        Calendar { week }
+    }
+
+    pub fn week(&self) -> u64 {
+        self.week
+    }
+
+    pub fn advance(&mut self) {
+        self.week += 1;
+    }
+
+    pub fn year(&self) -> u64 {
+        self.week / WEEKS_PER_YEAR + 1
+    }
+
+    pub fn month(&self) -> Month {
+        Month::ALL[(self.week % WEEKS_PER_YEAR / WEEKS_PER_MONTH) as usize]
+    }
+
+    /// Week of the month, from 1.
+    pub fn week_of_month(&self) -> u64 {
+        self.week % WEEKS_PER_MONTH + 1
+    }
+
+    pub fn season(&self) -> Season {
+        self.month().season()
+    }
+
+    /// Games held this week, if any.
+    pub fn games(&self) -> Option<Games> {
+        let month = self.month();
+        let festival = Games::FESTIVALS
+            .into_iter()
+            .find(|games| matches!(games.date(), Some((held_in, _)) if held_in == month));
+        match festival {
+            Some(games) => games
+                .date()
+                .filter(|(_, week)| *week == self.week_of_month())
+                .map(|_| games),
+            None if month.season().is_off_season() => None,
+            None if self.week_of_month() == 1 => Some(Games::Munera),
+            None => None,
+        }
+    }
+
+    /// The next `count` games from this week on, with their dates.
+    pub fn upcoming(&self, count: usize) -> Vec<(Calendar, Games)> {
+        (self.week..self.week + WEEKS_PER_YEAR)
+            .map(Calendar::new)
+            .filter_map(|date| date.games().map(|games| (date, games)))
+            .take(count)
+            .collect()
+    }
+
+    pub fn weeks_until(&self, date: &Calendar) -> u64 {
+        date.week.saturating_sub(self.week)
+    }
+
+    /// The next games to plan training around.
+    pub fn upcoming_table(&self, count: usize) -> Table {
+        let mut table = Table::new(&["Upcoming Games", "When", "Purse"]);
+        for (date, games) in self.upcoming(count) {
+            let when = match self.weeks_until(&date) {
+                0 => "This week".to_string(),
+                1 => "Next week".to_string(),
+                weeks => format!("In {} weeks", weeks),
+            };
+            let purse = match games.purse_bonus() {
+                0 => "Regular".to_string(),
+                bonus => format!("+{}%", bonus),
+            };
+            table.rows.push(vec![games.to_string(), when, purse]);
+        }
+        table
+    }
+}
@@ -0,0 +1,187 @@
+use super::items::catalog;
+use super::items::item::Item;
+use super::player::Player;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Points a new gladiator distributes over their attributes.
+pub const ATTRIBUTE_POINTS: u8 = 5;
+
+// Attributes before the origin bonus and spent points
+const BASE_STRENGTH: u8 = 5;
+const BASE_AGILITY: u8 = 5;
+const BASE_DEFENSE: u8 = 1;
+const BASE_ENDURANCE: u8 = 5;
+
+/// How the player ended up in the ludus.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Origin {
+    PrisonerOfWar,
+    Debtor,
+    Volunteer,
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Origin::PrisonerOfWar => write!(f, "Prisoner of War"),
+            Origin::Debtor => write!(f, "Debtor"),
+            Origin::Volunteer => write!(f, "Volunteer"),
+        }
+    }
+}
+
+impl Origin {
+    pub const ALL: [Origin; 3] = [Origin::PrisonerOfWar, Origin::Debtor, Origin::Volunteer];
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Origin::PrisonerOfWar => {
+                "Captured on the frontier and sold to the ludus. +2 strength, +1 defense."
+            }
+            Origin::Debtor => {
+                "Sold to pay your debts, with a few coins hidden away. +2 agility, 100 coins."
+            }
+            Origin::Volunteer => {
+                "You sold yourself for glory and came prepared. +2 endurance, 50 coins, a leather cap."
+            }
+        }
+    }
+
+    fn bonus(&self, attribute: Attribute) -> u8 {
+        match (self, attribute) {
+            (Origin::PrisonerOfWar, Attribute::Strength) => 2,
+            (Origin::PrisonerOfWar, Attribute::Defense) => 1,
+            (Origin::Debtor, Attribute::Agility) => 2,
+            (Origin::Volunteer, Attribute::Endurance) => 2,
+            _ => 0,
+        }
+    }
+
+    fn money(&self) -> i32 {
+        match self {
+            Origin::PrisonerOfWar => 0,
+            Origin::Debtor => 100,
+            Origin::Volunteer => 50,
+        }
+    }
+
+    fn items(&self) -> Vec<Item> {
+        match self {
+            Origin::Volunteer => catalog::armors()
+                .into_iter()
+                .filter(|armor| armor.name == "Leather Cap")
+                .map(Item::Armor)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Attribute {
+    Strength,
+    Agility,
+    Defense,
+    Endurance,
+}
+
+impl fmt::Display for Attribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Attribute::Strength => write!(f, "Strength"),
+            Attribute::Agility => write!(f, "Agility"),
+            Attribute::Defense => write!(f, "Defense"),
+            Attribute::Endurance => write!(f, "Endurance"),
+        }
+    }
+}
+
+impl Attribute {
+    pub const ALL: [Attribute; 4] = [
+        Attribute::Strength,
+        Attribute::Agility,
+        Attribute::Defense,
+        Attribute::Endurance,
+    ];
+
+    fn base(&self) -> u8 {
+        match self {
+            Attribute::Strength => BASE_STRENGTH,
+            Attribute::Agility => BASE_AGILITY,
+            Attribute::Defense => BASE_DEFENSE,
+            Attribute::Endurance => BASE_ENDURANCE,
+        }
+    }
+
+    fn index(&self) -> usize {
+        match self {
+            Attribute::Strength => 0,
+            Attribute::Agility => 1,
+            Attribute::Defense => 2,
+            Attribute::Endurance => 3,
+        }
+    }
+}
+
+/// A gladiator being created: name, origin and attribute points spent so far.
+#[derive(Debug, Clone)]
+pub struct Character {
+    pub name: String,
+    pub origin: Origin,
+    spent: [u8; 4],
+}
+
+impl Character {
+    /// A prisoner of war, the original story, with the points spread evenly.
+    pub fn new(name: String) -> Self {
+        let mut character = Character {
+            name,
+            origin: Origin::PrisonerOfWar,
+            spent: [0; 4],
+        };
+        for attribute in Attribute::ALL.iter().cycle() {
+            if !character.spend(*attribute) {
+                break;
+            }
+        }
+        character
+    }
+
+    pub fn points_left(&self) -> u8 {
+        ATTRIBUTE_POINTS - self.spent.iter().sum::<u8>()
+    }
+
+    /// Returns false when there are no points left.
+    pub fn spend(&mut self, attribute: Attribute) -> bool {
+        if self.points_left() == 0 {
+            return false;
+        }
+        self.spent[attribute.index()] += 1;
+        true
+    }
+
+    pub fn reset_points(&mut self) {
+        self.spent = [0; 4];
+    }
+
+    /// Final value of an attribute with the origin bonus and spent points.
+    pub fn attribute(&self, attribute: Attribute) -> u8 {
+        attribute.base() + self.origin.bonus(attribute) + self.spent[attribute.index()]
+    }
+
+    pub fn create_player(&self) -> Player {
+        let mut player = Player::new(self.name.clone(), self.origin);
+        player.strength = self.attribute(Attribute::Strength);
+        player.agility = self.attribute(Attribute::Agility);
+        player.defense = self.attribute(Attribute::Defense);
+        player.endurance = self.attribute(Attribute::Endurance);
+        player.health = player.max_health();
+        player.energy = player.max_energy();
+        player.money = self.origin.money();
+        for item in self.origin.items() {
+            player.inventory.add_item(item);
+        }
+        player
+    }
+}
@@ -0,0 +1,398 @@
+use super::crowd::CrowdMood;
+use super::enemy::Enemy;
+use super::injury::{BodyPart, Injury};
+use super::items::armor::Coverage;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// Damage dealt with bare hands when no weapon is equipped
+const UNARMED_MIN_DAMAGE: u8 = 1;
+const UNARMED_MAX_DAMAGE: u8 = 2;
+// Crowd excitement is kept between 0 and this
+pub const MAX_EXCITEMENT: u32 = 100;
+// A blow of at least this much damage gets the crowd on its feet
+const BIG_HIT_DAMAGE: i32 = 10;
+// Below this share of health every exchange is a close call
+const CLOSE_CALL_HEALTH: f32 = 0.25;
+// Tiredness goes from 0 (fresh) to this (exhausted)
+pub const MAX_TIREDNESS: u8 = 100;
+// Every fighter has this much health and energy, plus more per point of endurance
+const BASE_HEALTH: i32 = 50;
+const HEALTH_PER_ENDURANCE: i32 = 10;
+const BASE_ENERGY: i32 = 75;
+const ENERGY_PER_ENDURANCE: i32 = 5;
+// Evasion is capped so that even the nimblest fighter can be hit
+const MAX_EVADE_CHANCE: f64 = 0.4;
+const WAIT_EVADE_BONUS: f64 = 0.1;
+// Blows landing on a part of the body without armor deal this much more damage, in percent
+const UNARMORED_DAMAGE: i32 = 25;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FightAction {
+    Attack,
+    Block,
+    Wait,
+}
+
+/// Health of a fighter with the given endurance.
+pub fn max_health(endurance: u8) -> i32 {
+    BASE_HEALTH + endurance as i32 * HEALTH_PER_ENDURANCE
+}
+
+/// Energy of a fighter with the given endurance.
+pub fn max_energy(endurance: u8) -> u8 {
+    (BASE_ENERGY + endurance as i32 * ENERGY_PER_ENDURANCE).min(u8::MAX as i32) as u8
+}
+
+/// Tiredness a fighter gains from spending `exertion` energy, relative
+/// to how much energy they have in the first place.
+pub fn tiredness_from(exertion: i32, max_energy: u8) -> i32 {
+    exertion * MAX_TIREDNESS as i32 / max_energy.max(1) as i32
+}
+
+impl FightAction {
+    /// Tiredness a turn of this action costs, waiting lets a fighter recover.
+    pub fn exertion(&self) -> i32 {
+        match self {
+            FightAction::Attack => 10,
+            FightAction::Block => 5,
+            FightAction::Wait => -15,
+        }
+    }
+}
+
+impl fmt::Display for FightAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FightAction::Attack => write!(f, "Attack"),
+            FightAction::Block => write!(f, "Block"),
+            FightAction::Wait => write!(f, "Wait"),
+        }
+    }
+}
+
+/// Everything the combat resolver needs to know about a fighter,
+/// collected from base stats and equipped items.
+#[derive(Debug, Clone)]
+pub struct CombatStats {
+    pub strength: u8,
+    pub agility: u8,
+    pub defense: u32,
+    pub coverage: Coverage,
+    pub min_damage: u8,
+    pub max_damage: u8,
+    pub block: u8,
+    pub weight: u32,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Strike {
+    Evaded,
+    Hit {
+        damage: i32,
+        blocked: i32,
+        location: BodyPart,
+    },
+}
+
+impl CombatStats {
+    pub fn new(strength: u8, agility: u8, defense: u32) -> Self {
+        CombatStats {
+            strength,
+            agility,
+            defense,
+            coverage: Coverage::default(),
+            min_damage: UNARMED_MIN_DAMAGE,
+            max_damage: UNARMED_MAX_DAMAGE,
+            block: 0,
+            weight: 0,
+        }
+    }
+
+    /// A tired fighter hits softer and moves slower,
+    /// losing up to half of both at full exhaustion.
+    pub fn tired(mut self, tiredness: u8) -> Self {
+        let tiredness = tiredness.min(MAX_TIREDNESS) as u32;
+        self.strength -= (self.strength as u32 * tiredness / 200) as u8;
+        self.agility -= (self.agility as u32 * tiredness / 200) as u8;
+        self
+    }
+
+    /// Chance to dodge an incoming blow. Every point of agility adds 2%,
+    /// every 5 units of carried weight take 1% away.
+    pub fn evade_chance(&self, action: FightAction) -> f64 {
+        let mut chance = (self.agility as f64 * 2.0 - self.weight as f64 / 5.0) / 100.0;
+        if action == FightAction::Wait {
+            chance += WAIT_EVADE_BONUS;
+        }
+        chance.clamp(0.0, MAX_EVADE_CHANCE)
+    }
+
+    /// Rolls the weapon damage and adds the fighter's strength on top.
+    pub fn roll_damage<R: Rng>(&self, rng: &mut R) -> i32 {
+        let roll = rng.gen_range(self.min_damage, self.max_damage.max(self.min_damage) + 1);
+        roll as i32 + self.strength as i32
+    }
+}
+
+/// Resolves a single blow from `attacker` against `defender`, taking into
+/// account what the defender is doing this turn and where the blow lands.
+pub fn resolve_strike<R: Rng>(
+    rng: &mut R,
+    attacker: &CombatStats,
+    defender: &CombatStats,
+    defender_action: FightAction,
+) -> Strike {
+    if rng.gen_bool(defender.evade_chance(defender_action)) {
+        return Strike::Evaded;
+    }
+
+    let raw = attacker.roll_damage(rng);
+    let location = Injury::roll_location(rng);
+    let armor = defender.coverage.defense(location) as i32;
+    let mut damage = raw - defender.defense as i32 - armor;
+    if armor == 0 {
+        damage += damage.max(0) * UNARMORED_DAMAGE / 100;
+    }
+    let mut damage = damage.max(1);
+    let mut blocked = (raw - damage).max(0);
+
+    if defender_action == FightAction::Block {
+        // Without a shield the defender can only parry with their weapon
+        let block = if defender.block > 0 {
+            defender.block as i32
+        } else {
+            defender.strength as i32 / 2
+        };
+        let absorbed = block.min(damage);
+        damage -= absorbed;
+        blocked += absorbed;
+    }
+
+    Strike::Hit {
+        damage,
+        blocked,
+        location,
+    }
+}
+
+/// A fight in progress against a single opponent.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Fight {
+    pub enemy: Enemy,
+    pub crowd_mood: CrowdMood,
+    pub excitement: u32,
+    pub last_action: Option<FightAction>,
+    pub turns: u32,
+    pub damage_dealt: i32,
+    pub damage_taken: i32,
+    /// Fought at the gate of the ludus during a revolt, not in the arena.
+    pub escape: bool,
+}
+
+impl Fight {
+    pub fn new(enemy: Enemy, crowd_mood: CrowdMood) -> Self {
+        Fight {
+            enemy,
+            crowd_mood,
+            excitement: crowd_mood.initial_excitement(),
+            last_action: None,
+            turns: 0,
+            damage_dealt: 0,
+            damage_taken: 0,
+            escape: false,
+        }
+    }
+
+    /// The fight for the gate, with no crowd watching.
+    pub fn escape(enemy: Enemy) -> Self {
+        Fight {
+            excitement: 0,
+            escape: true,
+            ..Fight::new(enemy, CrowdMood::Restless)
+        }
+    }
+
+    /// How much the crowd enjoyed the fight, 0 - 100.
+    /// Long fights with plenty of blood on both sides are the best show,
+    /// as long as the crowd stayed excited.
+    pub fn spectacle(&self) -> u32 {
+        let bloodshed = (self.damage_dealt + self.damage_taken).max(0) as u32 / 2;
+        ((bloodshed + self.turns * 3).min(100) + self.excitement) / 2
+    }
+
+    /// Moves the crowd's excitement after a turn. Hits, dodges, varied and
+    /// bold play excite the stands, waiting around and repeating yourself bores them.
+    pub fn excite(
+        &mut self,
+        action: FightAction,
+        player_strike: Option<Strike>,
+        enemy_strike: Option<Strike>,
+        player_health: f32,
+    ) {
+        let mut change: i32 = match action {
+            FightAction::Attack => 2,
+            FightAction::Block => 0,
+            FightAction::Wait => -3,
+        };
+        change += match self.last_action {
+            Some(FightAction::Wait) if action == FightAction::Wait => -8,
+            Some(last) if last == action => -2,
+            Some(_) => 2,
+            None => 0,
+        };
+        for strike in [player_strike, enemy_strike].into_iter().flatten() {
+            change += match strike {
+                Strike::Evaded => 3,
+                Strike::Hit { damage, .. } if damage >= BIG_HIT_DAMAGE => 8,
+                Strike::Hit { damage: 0, .. } => 1,
+                Strike::Hit { .. } => 3,
+            };
+        }
+        // Fighting on while bleeding out is what they came to see
+        if player_health < CLOSE_CALL_HEALTH && action == FightAction::Attack {
+            change += 8;
+        }
+
+        self.excitement = (self.excitement as i32 + change).clamp(0, MAX_EXCITEMENT as i32) as u32;
+        self.last_action = Some(action);
+    }
+
+    /// Coins thrown into the sand on top of the purse, up to half of it.
+    pub fn crowd_bonus(&self, purse: i32) -> i32 {
+        purse * self.excitement as i32 / (2 * MAX_EXCITEMENT as i32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::rng::GameRng;
+
+    fn fighter(strength: u8, min_damage: u8, max_damage: u8) -> CombatStats {
+        CombatStats {
+            min_damage,
+            max_damage,
+            ..CombatStats::new(strength, 0, 0)
+        }
+    }
+
+    // Hits for exactly 10 and can't dodge
+    fn striker() -> CombatStats {
+        fighter(0, 10, 10)
+    }
+
+    fn covered(defense: u32) -> Coverage {
+        Coverage {
+            head: defense,
+            torso: defense,
+            arms: defense,
+            legs: defense,
+        }
+    }
+
+    fn hit(defender: &CombatStats, action: FightAction) -> (i32, i32) {
+        match resolve_strike(&mut GameRng::new(1), &striker(), defender, action) {
+            Strike::Hit {
+                damage, blocked, ..
+            } => (damage, blocked),
+            Strike::Evaded => panic!("a fighter without agility can't evade"),
+        }
+    }
+
+    #[test]
+    fn damage_stays_within_the_weapon_range_plus_strength() {
+        let mut rng = GameRng::new(1);
+        let attacker = fighter(6, 3, 8);
+        let rolls: Vec<i32> = (0..500).map(|_| attacker.roll_damage(&mut rng)).collect();
+        assert!(rolls.iter().all(|damage| (9..=14).contains(damage)));
+        assert!(rolls.contains(&9) && rolls.contains(&14));
+
+        // Fully exhausted, half the strength is gone
+        let tired = attacker.tired(MAX_TIREDNESS);
+        assert!((0..500)
+            .map(|_| tired.roll_damage(&mut rng))
+            .all(|damage| (6..=11).contains(&damage)));
+    }
+
+    #[test]
+    fn a_broken_damage_range_rolls_the_minimum() {
+        let mut rng = GameRng::new(1);
+        assert_eq!(fighter(0, 5, 2).roll_damage(&mut rng), 5);
+    }
+
+    #[test]
+    fn bare_skin_takes_more_damage_than_armor() {
+        assert_eq!(hit(&fighter(0, 1, 1), FightAction::Attack), (12, 0));
+
+        let armored = CombatStats {
+            coverage: covered(4),
+            ..fighter(0, 1, 1)
+        };
+        assert_eq!(hit(&armored, FightAction::Attack), (6, 4));
+    }
+
+    #[test]
+    fn every_hit_deals_at_least_one_damage_before_blocking() {
+        let fortress = CombatStats {
+            defense: 50,
+            coverage: covered(50),
+            ..fighter(0, 1, 1)
+        };
+        assert_eq!(hit(&fortress, FightAction::Attack), (1, 9));
+    }
+
+    #[test]
+    fn blocking_absorbs_damage() {
+        let shield = CombatStats {
+            block: 5,
+            ..fighter(0, 1, 1)
+        };
+        assert_eq!(hit(&shield, FightAction::Block), (7, 5));
+
+        // Without a shield the weapon parries half the strength
+        assert_eq!(hit(&fighter(8, 1, 1), FightAction::Block), (8, 4));
+
+        let tower = CombatStats {
+            block: 50,
+            ..fighter(0, 1, 1)
+        };
+        assert_eq!(hit(&tower, FightAction::Block), (0, 12));
+    }
+
+    #[test]
+    fn evasion_is_capped_and_weighed_down() {
+        let nimble = CombatStats::new(0, 50, 0);
+        assert_eq!(nimble.evade_chance(FightAction::Attack), MAX_EVADE_CHANCE);
+        assert_eq!(nimble.evade_chance(FightAction::Wait), MAX_EVADE_CHANCE);
+
+        let agile = CombatStats::new(0, 10, 0);
+        assert!((agile.evade_chance(FightAction::Attack) - 0.2).abs() < 1e-9);
+        assert!((agile.evade_chance(FightAction::Wait) - 0.3).abs() < 1e-9);
+
+        let loaded = CombatStats {
+            weight: 200,
+            ..agile
+        };
+        assert_eq!(loaded.evade_chance(FightAction::Attack), 0.0);
+    }
+
+    #[test]
+    fn nobody_evades_without_agility_unless_waiting() {
+        let mut rng = GameRng::new(1);
+        let defender = fighter(0, 1, 1);
+        for action in [FightAction::Attack, FightAction::Block] {
+            assert!((0..200).all(|_| matches!(
+                resolve_strike(&mut rng, &striker(), &defender, action),
+                Strike::Hit { .. }
+            )));
+        }
+        assert!((0..200).any(|_| resolve_strike(
+            &mut rng,
+            &striker(),
+            &defender,
+            FightAction::Wait
+        ) == Strike::Evaded));
+    }
+}
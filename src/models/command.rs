@@ -0,0 +1,29 @@
+use super::combat::FightAction;
+use super::inventory::ItemId;
+use super::items::item::Slot;
+use super::training::Regimen;
+
+/// Everything a frontend can ask the game to do.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Command {
+    Train(Regimen),
+    Rest,
+    Buy(ItemId),
+    Equip(ItemId),
+    /// Has the blacksmith mend the item equipped in a slot.
+    Repair(Slot),
+    StartFight,
+    SkipFight,
+    Fight(FightAction),
+    BegForMercy,
+    RefuseMercy,
+    SpareEnemy,
+    KillEnemy,
+    /// Takes one of the beaten opponent's items, by its position in the spoils.
+    Claim(usize),
+    LeaveSpoils,
+    BuyFreedom,
+    Recruit,
+    PrepareRevolt,
+    Revolt,
+}
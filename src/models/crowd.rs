@@ -0,0 +1,73 @@
+use super::player::Fame;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// Bounds of any missio chance, the crowd is never completely predictable
+const MIN_MISSIO_CHANCE: f64 = 0.05;
+const MAX_MISSIO_CHANCE: f64 = 0.95;
+// A perfect show is worth this much on top of the base chance
+const SPECTACLE_WEIGHT: f64 = 0.3;
+
+/// The mood of the stands on fight day, which sways every missio.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CrowdMood {
+    Bloodthirsty,
+    Restless,
+    Generous,
+}
+
+impl fmt::Display for CrowdMood {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CrowdMood::Bloodthirsty => write!(f, "Bloodthirsty"),
+            CrowdMood::Restless => write!(f, "Restless"),
+            CrowdMood::Generous => write!(f, "Generous"),
+        }
+    }
+}
+
+impl CrowdMood {
+    pub fn roll<R: Rng>(rng: &mut R) -> CrowdMood {
+        match rng.gen_range(0, 4) {
+            0 => CrowdMood::Bloodthirsty,
+            3 => CrowdMood::Generous,
+            _ => CrowdMood::Restless,
+        }
+    }
+
+    /// Excitement of the stands when the fighters walk in.
+    pub fn initial_excitement(&self) -> u32 {
+        match self {
+            CrowdMood::Bloodthirsty => 30,
+            CrowdMood::Restless => 10,
+            CrowdMood::Generous => 20,
+        }
+    }
+
+    fn modifier(&self) -> f64 {
+        match self {
+            CrowdMood::Bloodthirsty => -0.2,
+            CrowdMood::Restless => 0.0,
+            CrowdMood::Generous => 0.15,
+        }
+    }
+
+    /// Chance that the crowd spares a defeated player. Fame sets the base,
+    /// a good show (0 - 100 spectacle) and the mood of the day move it.
+    pub fn missio_chance(&self, fame: Fame, spectacle: u32) -> f64 {
+        self.chance(fame.mercy_chance(), spectacle)
+    }
+
+    /// Chance that the crowd wants a defeated opponent spared, judged by
+    /// their fame and how well they fought.
+    pub fn enemy_missio_chance(&self, enemy_fame: u8, spectacle: u32) -> f64 {
+        self.chance(0.3 + enemy_fame as f64 / 200.0, spectacle)
+    }
+
+    fn chance(&self, base: f64, spectacle: u32) -> f64 {
+        let performance = spectacle.min(100) as f64 / 100.0 * SPECTACLE_WEIGHT;
+        (base - SPECTACLE_WEIGHT / 2.0 + performance + self.modifier())
+            .clamp(MIN_MISSIO_CHANCE, MAX_MISSIO_CHANCE)
+    }
+}
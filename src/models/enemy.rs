@@ -1,21 +1,204 @@
-use super::items::armor::Armor;
-use super::items::hand_item::HandItem;
+use super::ai::{Behavior, Situation};
+use super::combat::{
+    max_energy, max_health, tiredness_from, CombatStats, FightAction, MAX_TIREDNESS,
+};
+use super::items::armor::{ArmorSet, ArmorType};
+use super::items::catalog;
+use super::items::hand_item::{HandItem, HandItemType};
+use super::items::item::Item;
+use super::utils::health_bar;
+use rand::seq::SliceRandom;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
+// Named opponents always fight the same way
+const ENEMY_NAMES: &[(&str, Behavior)] = &[
+    ("Crixus", Behavior::Aggressive),
+    ("Gannicus", Behavior::Berserker),
+    ("Oenomaus", Behavior::Defensive),
+    ("Varro", Behavior::Cautious),
+    ("Priscus", Behavior::Defensive),
+    ("Verus", Behavior::Aggressive),
+    ("Flamma", Behavior::Berserker),
+    ("Carpophorus", Behavior::Cautious),
+    ("Tetraites", Behavior::Aggressive),
+    ("Spiculus", Behavior::Defensive),
+    ("Marcus Attilius", Behavior::Cautious),
+    ("Hermes", Behavior::Berserker),
+];
+// The guard captain is this many tiers above the player's arena opponents...
+const CAPTAIN_TIERS: i32 = 3;
+// ...but every recruit keeps his men busy and costs him this much health
+const RECRUIT_DAMAGE: i32 = 10;
+
 #[derive(Serialize, Deserialize, Debug)]
-struct Enemy {
-    name: String,
-    fame: u8,
-    tiredness_level: u8, // max 255
-    health: i32,
-    max_health: i32,
-    strength: u8,
-    defense: u8,
-    agility: u8,
-    left_hand: HandItem,
-    right_hand: Option<HandItem>,
-    helmet: Option<Armor>,
-    breastplate: Option<Armor>,
-    legs: Option<Armor>,
-    money: i32,
+pub struct Enemy {
+    pub name: String,
+    pub fame: u8,
+    pub tiredness_level: u8, // 0 - MAX_TIREDNESS
+    pub health: i32,
+    pub strength: u8,
+    pub defense: u8,
+    pub agility: u8,
+    pub endurance: u8,
+    pub left_hand: HandItem,
+    pub right_hand: Option<HandItem>,
+    pub armor: ArmorSet,
+    pub money: i32,
+    pub behavior: Behavior,
+}
+
+impl Enemy {
+    /// Creates an opponent matched to the player's standing in the arena.
+    /// Every victory and every 10 points of fame make the next enemy a tier tougher.
+    pub fn generate<R: Rng>(rng: &mut R, player_fame: i32, player_victories: i32) -> Enemy {
+        let tier = (player_victories + player_fame.max(0) / 10).clamp(0, 20) as u8;
+
+        let strength = 3 + tier * 2 + rng.gen_range(0, 3);
+        let agility = 3 + tier + rng.gen_range(0, 3);
+        let defense = tier / 2;
+        let endurance = 1 + tier + rng.gen_range(0, 2);
+
+        // Pick among the weapons the enemy is strong enough to wield,
+        // falling back to the lightest one in the catalog
+        let mut weapons = catalog::weapons();
+        weapons.sort_by_key(|weapon| weapon.req_strength);
+        let usable = weapons
+            .iter()
+            .filter(|weapon| weapon.req_strength <= strength)
+            .count()
+            .max(1);
+        let weapon = weapons[..usable].choose(rng).unwrap().clone();
+
+        // Better equipped enemies show up as the player climbs the ranks
+        let gear_chance = (0.2 + tier as f64 * 0.1).min(0.9);
+
+        let right_hand = if weapon.item_type == HandItemType::Single && rng.gen_bool(gear_chance) {
+            catalog::shields()
+                .into_iter()
+                .filter(|shield| shield.req_strength <= strength)
+                .collect::<Vec<_>>()
+                .choose(rng)
+                .cloned()
+                .map(HandItem::Shield)
+        } else {
+            None
+        };
+
+        let mut armor = ArmorSet::default();
+        for armor_type in ArmorType::ALL {
+            if !rng.gen_bool(gear_chance) {
+                continue;
+            }
+            if let Some(piece) = catalog::armors()
+                .into_iter()
+                .filter(|armor| armor.armor_type == armor_type && armor.req_strength <= strength)
+                .collect::<Vec<_>>()
+                .choose(rng)
+            {
+                armor.equip(piece.clone());
+            }
+        }
+
+        let (name, behavior) = *ENEMY_NAMES.choose(rng).unwrap();
+
+        Enemy {
+            name: name.to_string(),
+            fame: (tier as u32 * 5).min(u8::MAX as u32) as u8,
+            tiredness_level: 0,
+            health: max_health(endurance),
+            strength,
+            defense,
+            agility,
+            endurance,
+            left_hand: HandItem::Weapon(weapon),
+            right_hand,
+            armor,
+            money: 10 + tier as i32 * 15 + rng.gen_range(0, 10),
+            behavior,
+        }
+    }
+
+    /// Everything the enemy carried into the arena.
+    pub fn gear(&self) -> Vec<Item> {
+        let hands = [Some(&self.left_hand), self.right_hand.as_ref()]
+            .into_iter()
+            .flatten()
+            .cloned()
+            .map(Item::from);
+        hands
+            .chain(self.armor.pieces().cloned().map(Item::Armor))
+            .collect()
+    }
+
+    /// The captain of the ludus guard, the last man between the rebels and the gate.
+    pub fn guard_captain<R: Rng>(
+        rng: &mut R,
+        player_fame: i32,
+        player_victories: i32,
+        recruits: u8,
+    ) -> Enemy {
+        let mut captain = Enemy::generate(rng, player_fame, player_victories + CAPTAIN_TIERS);
+        captain.name = "Captain of the Guard".to_string();
+        captain.behavior = Behavior::Defensive;
+        captain.health = (captain.health - recruits as i32 * RECRUIT_DAMAGE).max(1);
+        captain
+    }
+
+    pub fn choose_action<R: Rng>(
+        &self,
+        rng: &mut R,
+        opponent_health: f32,
+        opponent_tiredness: u8,
+    ) -> FightAction {
+        let situation = Situation {
+            own_health: self.health as f32 / self.max_health() as f32,
+            opponent_health,
+            own_tiredness: self.tiredness_level,
+            opponent_tiredness,
+        };
+        self.behavior.choose_action(rng, &situation)
+    }
+
+    /// Attacking wears the enemy out, waiting lets them recover.
+    pub fn tire(&mut self, action: FightAction) {
+        let tiredness = tiredness_from(action.exertion(), max_energy(self.endurance));
+        self.tiredness_level =
+            (self.tiredness_level as i32 + tiredness).clamp(0, MAX_TIREDNESS as i32) as u8;
+    }
+
+    pub fn max_health(&self) -> i32 {
+        max_health(self.endurance)
+    }
+
+    pub fn take_damage(&mut self, amount: i32) {
+        self.health = (self.health - amount).max(0);
+    }
+
+    pub fn health_bar(&self) -> String {
+        health_bar(self.health, self.max_health())
+    }
+
+    fn hand_items(&self) -> impl Iterator<Item = &HandItem> {
+        std::iter::once(&self.left_hand).chain(self.right_hand.as_ref())
+    }
+
+    pub fn combat_stats(&self) -> CombatStats {
+        let mut stats = CombatStats::new(self.strength, self.agility, self.defense as u32);
+        stats.coverage = self.armor.coverage();
+        stats.weight = self.armor.weight();
+        for item in self.hand_items() {
+            match item {
+                HandItem::Weapon(weapon) => {
+                    stats.min_damage = weapon.min_damage;
+                    stats.max_damage = weapon.max_damage;
+                }
+                HandItem::Shield(shield) => {
+                    stats.block = shield.block_damage;
+                    stats.weight += shield.weight.max(0) as u32;
+                }
+            }
+        }
+        stats.tired(self.tiredness_level)
+    }
 }
@@ -0,0 +1,166 @@
+use super::ai::Behavior;
+use super::calendar::{Calendar, Games, Season};
+use super::combat::{FightAction, Strike};
+use super::crowd::CrowdMood;
+use super::injury::Injury;
+use super::player::Fame;
+use super::training::Regimen;
+
+/// Outcome of a command, for the frontend to present however it likes.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Event {
+    WeekAdvanced {
+        date: Calendar,
+        games: Option<Games>,
+    },
+    SeasonChanged {
+        season: Season,
+    },
+    /// Training report for a week of drills.
+    Trained {
+        regimen: Regimen,
+        paid: i32,
+        before: u8,
+        after: u8,
+        energy_before: u8,
+        energy_after: u8,
+    },
+    CannotTrainInjured,
+    TooTiredToTrain {
+        energy: u8,
+    },
+    Rested {
+        healed: i32,
+        health: i32,
+        max_health: i32,
+        energy: u8,
+    },
+    Purchased {
+        name: String,
+        price: i32,
+    },
+    NotEnoughMoney {
+        name: String,
+        price: i32,
+        money: i32,
+    },
+    Equipped {
+        name: String,
+    },
+    CannotWieldInjured {
+        name: String,
+    },
+    StrengthTooLow {
+        name: String,
+        required: u8,
+        current: u8,
+    },
+    Repaired {
+        name: String,
+        price: i32,
+    },
+    NothingToRepair {
+        name: String,
+    },
+    CannotAffordRepair {
+        name: String,
+        price: i32,
+        money: i32,
+    },
+    /// A piece of the player's gear gave out during a fight.
+    ItemBroken {
+        name: String,
+    },
+    NoSuchItem,
+    NotFightWeek,
+    InjuredCannotFight,
+    NotReadyToFight,
+    FightSkipped {
+        fame_lost: i32,
+    },
+    FightStarted {
+        enemy: String,
+        behavior: Behavior,
+        mood: CrowdMood,
+        games: Games,
+    },
+    PlayerAction(FightAction),
+    PlayerStrike {
+        strike: Strike,
+        enemy_action: FightAction,
+    },
+    EnemyAction(FightAction),
+    EnemyStrike(Strike),
+    Victory {
+        purse: i32,
+        lanista_share: i32,
+        crowd_bonus: i32,
+        festival_bonus: i32,
+        first: bool,
+    },
+    /// The beaten opponent's gear is up for claiming.
+    SpoilsOffered {
+        claims: usize,
+    },
+    SpoilClaimed {
+        name: String,
+    },
+    /// Whatever the player left behind goes to the lanista.
+    SpoilsLeft {
+        sold: usize,
+    },
+    Defeated,
+    Injured {
+        injury: Injury,
+    },
+    InjuryHealed {
+        injury: Injury,
+    },
+    EnemyDefeated {
+        crowd_wants_mercy: bool,
+    },
+    EnemySpared {
+        obeyed_crowd: bool,
+    },
+    EnemyKilled {
+        obeyed_crowd: bool,
+    },
+    MercyGranted,
+    Killed {
+        begged: bool,
+    },
+    FreedomBought,
+    FreedomRefused {
+        price: i32,
+        money: i32,
+    },
+    /// The player is now someone the other gladiators would follow.
+    RebellionUnlocked,
+    Recruited {
+        recruits: u8,
+        needed: u8,
+    },
+    RecruitRefused,
+    RevoltPrepared {
+        progress: u8,
+        preparation: u8,
+    },
+    PlotDiscovered {
+        recruits_lost: u8,
+    },
+    RevoltStarted {
+        enemy: String,
+        recruits: u8,
+    },
+    Escaped,
+    RevoltCrushed,
+    FameChanged {
+        change: i32,
+        fame: i32,
+    },
+    FameLevelChanged {
+        level: Fame,
+        promoted: bool,
+    },
+    CommandUnavailable,
+}
@@ -1,558 +1,910 @@
-use super::items::item::{Equipable, Item};
+use super::calendar::Calendar;
+use super::character::Character;
+use super::combat::{resolve_strike, Fight, FightAction, Strike};
+use super::command::Command;
+use super::crowd::CrowdMood;
+use super::enemy::Enemy;
+use super::event::Event;
+use super::injury::{BodyPart, Injury, Severity};
+use super::inventory::ItemId;
+use super::items::armor::ArmorType;
+use super::items::hand_item::HandItemType;
+use super::items::item::{Equipable, Item, Slot};
 use super::player::Player;
+use super::rebellion::{Rebellion, Stage, RECRUITS_NEEDED};
+use super::rng::GameRng;
+use super::spoils::Spoils;
 use super::store::Store;
-use super::utils::{clear_screen, print_line, print_logo, slow_type};
-use console::Term;
-use dialoguer::theme::ColorfulTheme;
-use dialoguer::Select;
+use super::training::{Regimen, DOCTORE_PRICE};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
-use std::any::TypeId;
-use std::fs::{self, File};
-use std::io::Read;
-use std::{io, option, process};
+
+const FREEDOM_PRICE: i32 = 1000;
+const SKIP_FIGHT_FAME_LOSS: i32 = 10;
+const MERCY_FAME_LOSS: i32 = 5;
+// Every victory is worth this much fame, more against famous opponents in a good show
+const VICTORY_FAME: i32 = 5;
+// Fame won or lost by following or defying the crowd's verdict
+const VERDICT_FAME: i32 = 5;
+// Change of the lanista's favor and the gladiators' respect after a verdict
+const VERDICT_RELATIONSHIP: i32 = 5;
+const REST_HEAL: i32 = 5;
+const REST_ENERGY: i32 = 50;
+// Energy recovered every week whatever the player does
+const WEEKLY_ENERGY: i32 = 10;
+const TRAIN_ENERGY: i32 = 25;
+// Below this much energy training is only half as effective
+const LOW_ENERGY: u8 = 40;
+// Punishment for a plot that comes to light
+const FLOGGING_DAMAGE: i32 = 20;
+const DISCOVERY_FAVOR_LOSS: i32 = 20;
+// Durability the player's gear loses with every blow it lands, blocks or takes
+const WEAPON_WEAR: u8 = 2;
+const SHIELD_WEAR: u8 = 3;
+const ARMOR_WEAR: u8 = 3;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Game {
     player: Player,
     store: Store,
-    state: GameState,
-    current_week: u64,
-    is_fight_week: bool,
+    phase: Phase,
+    calendar: Calendar,
+    rebellion: Rebellion,
+    rng: GameRng,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
-enum GameState {
-    MainMenu,
-    Loading,
-    InGame,
-    GameOver,
-    Inventory,
-    Exit,
+pub enum Phase {
+    Ludus,
+    Fighting(Fight),
+    Defeated(Fight),
+    /// The opponent is down and the player decides whether they live.
+    Verdict {
+        fight: Fight,
+        crowd_wants_mercy: bool,
+    },
+    /// The player picks from the beaten opponent's gear.
+    Spoils(Spoils),
+    Over(Ending),
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Ending {
+    Freedom,
+    /// Broke out of the ludus at the head of a revolt.
+    Escape,
+    Death,
 }
 
 impl Game {
-    pub fn new() -> Game {
-        let player: Player = Player::new("Playername".to_string());
-        let store: Store = Store::new();
+    pub fn new(character: &Character) -> Game {
+        Game::with_rng(character, GameRng::from_entropy())
+    }
+
+    pub fn with_seed(character: &Character, seed: u64) -> Game {
+        Game::with_rng(character, GameRng::new(seed))
+    }
+
+    fn with_rng(character: &Character, mut rng: GameRng) -> Game {
+        let player: Player = character.create_player();
+        let store: Store = Store::new(&mut rng);
 
         Game {
             player,
             store,
-            state: GameState::MainMenu,
-            current_week: 0,
-            is_fight_week: false,
+            phase: Phase::Ludus,
+            // Every story starts in the arena, with the munera of Martius
+            calendar: Calendar::default(),
+            rebellion: Rebellion::default(),
+            rng,
         }
     }
 
-    fn advance_time(&mut self) {
-        // Increment the week counter here
-        self.current_week += 1;
-        self.is_fight_week = self.current_week % 4 == 0;
+    pub fn player(&self) -> &Player {
+        &self.player
     }
 
-    pub fn main_loop(&mut self) {
-        loop {
-            match self.state {
-                GameState::MainMenu => self.main_menu(),
-                GameState::InGame => self.ludus_menu(),
-                GameState::GameOver => self.end_game(),
-                GameState::Loading => slow_type("Loading game"),
-                GameState::Exit => self.exit(),
-                GameState::Inventory => slow_type("Show inventory!"),
-            }
-        }
+    pub fn store(&self) -> &Store {
+        &self.store
     }
 
-    fn exit(&mut self) {
-        // slow_type("Bye!");
-        process::exit(0);
+    pub fn phase(&self) -> &Phase {
+        &self.phase
     }
 
-    fn main_menu(&mut self) {
-        let term = Term::stdout();
-        term.clear_screen().unwrap();
-        print_line();
-        print_logo();
-        print_line();
-        let main_menu_options = vec!["New Game".to_string(), "Load Game".to_string(), "Scores".to_string(), "Exit".to_string()];
-        let main_selection = self.get_selection("Main Menu", &main_menu_options);
-        match main_selection {
-            0 => self.new_game(),
-            1 => self.load_game_menu(), // Call the function to handle loading a game
-            2 => slow_type("Showing high scores..."),
-            3 => self.state = GameState::Exit,
-            _ => unreachable!(),
-        }
+    pub fn current_week(&self) -> u64 {
+        self.calendar.week()
     }
 
-    fn buy_freedom(&mut self) {
-        slow_type("As you approach the owner of the ludus, your heart races with a mix of hope and anxiety. In your hand is a pouch of coins, meticulously saved over the years, representing your earnest attempt to buy your own freedom.");
-        if self.player.money >= 1000 {
-            slow_type("To your immense relief, the owner nods in acceptance, a greedy glint in his eye as he takes the hefty pouch of coins. Your eyes light up with disbelief and gratitude. With the shackles of servitude finally broken, you step out of the ludus, a free man, walking into a new life filled with endless possibilities...");
-            self.state = GameState::GameOver;
-        } else {
-            slow_type("With a deep breath, you present your offer, only to see a frown crease the owner's face. He weighs the coins with a dismissive glance and declares it insufficient, his words crushing your hopes like fragile leaves underfoot. You return to the confines of the ludus, and your dream of freedom slipping away like sand through your fingers.");
-            self.state = GameState::InGame;
-        }
+    pub fn calendar(&self) -> &Calendar {
+        &self.calendar
     }
 
-    fn player_info(&self) {
-        self.player.player_info();
-        self.player.equipment.to_pretty_table();
-        clear_screen();
+    pub fn rebellion(&self) -> &Rebellion {
+        &self.rebellion
     }
 
-    fn player_inventory(&mut self) {
-        let inventory_options = vec!["Weapons".to_string(), "Shields".to_string(), "Helmets".to_string(), "Back to Ludus".to_string()];
-        let inventory_selection = self.get_selection("Inventory", &inventory_options);
+    pub fn is_fight_week(&self) -> bool {
+        self.calendar.games().is_some()
+    }
 
-        match inventory_selection {
-            0 => {
-                slow_type("Weapons");
-                self.player.inventory.print_all_weapons();
-                self.player_inventory_equip_weapon();
-            }
-            1 => {
-                slow_type("Shields");
-                self.player.inventory.print_all_shields();
-                self.player_inventory_equip_shield();
-            }
-            2 => {
-                slow_type("Armor");
-                self.player.inventory.print_all_helmets();
-                self.player_inventory_equip_helmet();
+    pub fn seed(&self) -> u64 {
+        self.rng.seed()
+    }
+
+    /// Applies a single command and reports everything that happened.
+    pub fn execute(&mut self, command: Command) -> Vec<Event> {
+        match (&self.phase, command) {
+            // The games can't be missed by keeping busy, only fought or skipped
+            (
+                Phase::Ludus,
+                Command::Train(_)
+                | Command::Rest
+                | Command::BuyFreedom
+                | Command::Recruit
+                | Command::PrepareRevolt
+                | Command::Revolt,
+            ) if self.is_fight_week() => vec![Event::CommandUnavailable],
+            (Phase::Ludus, Command::Train(regimen)) => self.train(regimen),
+            (Phase::Ludus, Command::Rest) => self.rest(),
+            (Phase::Ludus, Command::Buy(id)) => self.buy(id),
+            (Phase::Ludus, Command::Equip(id)) => self.equip(id),
+            (Phase::Ludus, Command::Repair(slot)) => self.repair(slot),
+            (Phase::Ludus, Command::StartFight) => self.start_fight(),
+            (Phase::Ludus, Command::SkipFight) => self.skip_fight(),
+            (Phase::Ludus, Command::BuyFreedom) => self.buy_freedom(),
+            (Phase::Ludus, Command::Recruit) => self.recruit(),
+            (Phase::Ludus, Command::PrepareRevolt) => self.prepare_revolt(),
+            (Phase::Ludus, Command::Revolt) => self.revolt(),
+            (Phase::Fighting(_), Command::Fight(action)) => self.fight_turn(action),
+            (Phase::Defeated(_), Command::BegForMercy) => self.beg_for_mercy(),
+            (Phase::Defeated(_), Command::RefuseMercy) => {
+                self.phase = Phase::Over(Ending::Death);
+                vec![Event::Killed { begged: false }]
             }
-            3 => self.ludus_menu(),
-            _ => unreachable!(),
+            (Phase::Verdict { .. }, Command::SpareEnemy) => self.verdict(true),
+            (Phase::Verdict { .. }, Command::KillEnemy) => self.verdict(false),
+            (Phase::Spoils(_), Command::Claim(index)) => self.claim(index),
+            (Phase::Spoils(_), Command::LeaveSpoils) => self.leave_spoils(),
+            _ => vec![Event::CommandUnavailable],
         }
     }
 
-    fn store_menu(&mut self) {
-        let inventory_options = vec!["Weapons".to_string(), "Shields".to_string(), "Helmets".to_string(), "Back to Ludus".to_string()];
-        let inventory_selection = self.get_selection("Store", &inventory_options);
-
-        match inventory_selection {
-            0 => {
-                slow_type("Weapons");
-                self.store.print_all_weapons();
-                self.buy_weapon_menu();
-            }
-            1 => {
-                self.store.print_all_shields();
-                slow_type("Shields");
-            }
-            2 => {
-                slow_type("Armor");
-            }
-            3 => self.ludus_menu(),
-            _ => unreachable!(),
+    /// Starts a new week, during which injuries heal a little.
+    fn advance_time(&mut self) -> Vec<Event> {
+        let season = self.calendar.season();
+        self.calendar.advance();
+        self.player.change_energy(WEEKLY_ENERGY);
+        let mut events = vec![Event::WeekAdvanced {
+            date: self.calendar,
+            games: self.calendar.games(),
+        }];
+        if self.calendar.season() != season {
+            events.push(Event::SeasonChanged {
+                season: self.calendar.season(),
+            });
         }
+        events.extend(self.heal_injuries());
+        if self.rebellion.stage == Stage::Dormant
+            && Rebellion::can_start(&self.player, self.calendar.week())
+        {
+            self.rebellion.stage = Stage::Recruiting;
+            events.push(Event::RebellionUnlocked);
+        }
+        events
     }
 
-    fn buy_weapon_menu(&mut self) {
-        let mut weapon_names = self.store.get_weapon_names();
-        weapon_names.push("Back to Store".to_string());
+    fn heal_injuries(&mut self) -> Vec<Event> {
+        self.player
+            .heal_injuries(1)
+            .into_iter()
+            .map(|injury| Event::InjuryHealed { injury })
+            .collect()
+    }
 
-        let store_selection = self.get_selection("Buy", &weapon_names);
+    /// Fame events for a change of the player's fame, including promotions and demotions.
+    fn change_fame(&mut self, change: i32) -> Vec<Event> {
+        let level = self.player.fame_level;
+        let change = self.player.change_fame(change);
+        let mut events = Vec::new();
+        if change != 0 {
+            events.push(Event::FameChanged {
+                change,
+                fame: self.player.fame,
+            });
+        }
+        if self.player.fame_level != level {
+            events.push(Event::FameLevelChanged {
+                level: self.player.fame_level,
+                promoted: self.player.fame_level > level,
+            });
+        }
+        events
+    }
 
-        if store_selection == weapon_names.len() - 1 {
-            // The player chose the "Back" option
-            self.store_menu();
-        } else {
-            let selected_weapon = self.store.weapons[store_selection].clone();
-            if self.player.money < selected_weapon.price {
-                slow_type(format!("Can not buy: {}", selected_weapon.name).as_str());
-                slow_type(
-                    format!(
-                        "Not enought money: Required {}  You have {}",
-                        selected_weapon.price, self.player.money
-                    )
-                    .as_str(),
-                );
-            } else {
-                let name = &selected_weapon.name.clone();
-                self.player.money -= &selected_weapon.price;
-                self.store.remove_weapon(store_selection);
-                self.player
-                    .inventory
-                    .add_item(Item::Weapon(selected_weapon));
-                slow_type(format!("Purchased: {}", name).as_str());
-            }
+    fn train(&mut self, regimen: Regimen) -> Vec<Event> {
+        //TODO: add special moves
+        if self.player.is_injured() {
+            return vec![Event::CannotTrainInjured];
         }
-        // clear_screen();
+        let paid = if regimen.doctore { DOCTORE_PRICE } else { 0 };
+        if self.player.money < paid {
+            return vec![Event::NotEnoughMoney {
+                name: "Doctore".to_string(),
+                price: paid,
+                money: self.player.money,
+            }];
+        }
+        if (self.player.energy as i32) < TRAIN_ENERGY {
+            return vec![Event::TooTiredToTrain {
+                energy: self.player.energy,
+            }];
+        }
+
+        let exhausted = self.player.energy < LOW_ENERGY;
+        let tiredness = self.player.tiredness();
+        let energy_before = self.player.energy;
+        let before = self.player.attribute(regimen.attribute);
+        self.player.money -= paid;
+        self.player.change_energy(-TRAIN_ENERGY);
+        let gain = regimen.roll_gain(&mut self.rng, before, exhausted);
+        self.player.raise(regimen.attribute, gain);
+
+        let mut events = vec![Event::Trained {
+            regimen,
+            paid,
+            before,
+            after: self.player.attribute(regimen.attribute),
+            energy_before,
+            energy_after: self.player.energy,
+        }];
+        if regimen.roll_injury(&mut self.rng, tiredness) {
+            let location = Injury::roll_location(&mut self.rng);
+            let injury = Injury::new(location, Severity::Light);
+            self.player.injuries.push(injury.clone());
+            events.push(Event::Injured { injury });
+        }
+        events.extend(self.advance_time());
+        events
     }
 
-    fn player_inventory_equip_weapon(&mut self) {
-        let mut weapon_names = self.player.inventory.get_weapon_names();
-        weapon_names.push("Back to Inventory".to_string());
+    fn rest(&mut self) -> Vec<Event> {
+        // TODO: figure out heal amout
+        let before = self.player.health;
+        self.player.heal(REST_HEAL);
+        self.player.change_energy(REST_ENERGY);
+        let mut events = vec![Event::Rested {
+            healed: self.player.health - before,
+            health: self.player.health,
+            max_health: self.player.max_health(),
+            energy: self.player.energy,
+        }];
+        // A week of rest heals injuries twice as fast
+        events.extend(self.heal_injuries());
+        events.extend(self.advance_time());
+        events
+    }
 
-        let inventory_selection = self.get_selection("Equip", &weapon_names);
+    fn buy(&mut self, id: ItemId) -> Vec<Event> {
+        let Some(item) = self.store.items.get(id) else {
+            return vec![Event::NoSuchItem];
+        };
+        let name = item.name().to_string();
+        let price = self.player.fame_level.price(item.price());
+
+        if self.player.money < price {
+            return vec![Event::NotEnoughMoney {
+                name,
+                price,
+                money: self.player.money,
+            }];
+        }
 
-        if inventory_selection == weapon_names.len() - 1 {
-            // The player chose the "Back" option
-            self.player_inventory();
-        } else {
-            println!("Equipping: {}", weapon_names[inventory_selection]);
-            let selected_weapon = self.player.inventory.weapons[inventory_selection].clone();
-            if self.player.strength < selected_weapon.req_strength {
-                slow_type(format!("Can not equip: {:?}", selected_weapon).as_str());
-                slow_type(
-                    format!(
-                        "Strength Required: {} Current Strength {}",
-                        selected_weapon.req_strength, self.player.strength
-                    )
-                    .as_str(),
-                );
-            } else {
-                slow_type(format!("Equipping: {:?}", selected_weapon).as_str());
-                self.player.equipment.equip_weapon(selected_weapon);
-            }
+        self.player.money -= price;
+        if let Some(item) = self.store.items.remove(id) {
+            self.player.inventory.add_item(item);
         }
-        // clear_screen();
+        vec![Event::Purchased { name, price }]
     }
 
-    fn get_selection(&self, promt: &str, options: &Vec<String>) -> usize {
-        Select::with_theme(&ColorfulTheme::default())
-            .with_prompt(promt)
-            .default(0)
-            .items(options)
-            .interact()
-            .unwrap()
+    fn equip(&mut self, id: ItemId) -> Vec<Event> {
+        let Some(item) = self.player.inventory.get(id).cloned() else {
+            return vec![Event::NoSuchItem];
+        };
+
+        let name = item.name().to_string();
+        let required = item.req_strength();
+        if let Item::Weapon(weapon) = &item {
+            if weapon.item_type == HandItemType::Double && !self.player.can_wield_two_handed() {
+                return vec![Event::CannotWieldInjured { name }];
+            }
+        }
+        if self.player.strength < required {
+            return vec![Event::StrengthTooLow {
+                name,
+                required,
+                current: self.player.strength,
+            }];
+        }
+
+        // Whatever the player was holding or wearing goes back to the inventory
+        let equipment = &mut self.player.equipment;
+        let replaced = match self.player.inventory.remove(id) {
+            Some(Item::Weapon(weapon)) => equipment.equip_weapon(weapon),
+            Some(Item::Shield(shield)) => equipment.equip_shield(shield),
+            Some(Item::Armor(armor)) => equipment
+                .equip_armor(armor)
+                .map(Item::Armor)
+                .into_iter()
+                .collect(),
+            None => Vec::new(),
+        };
+        for item in replaced {
+            self.player.inventory.add_item(item);
+        }
+        vec![Event::Equipped { name }]
     }
 
-    fn player_inventory_equip_shield(&mut self) {
-        let mut shield_names = self.player.inventory.get_shields_names();
-        shield_names.push("Back to Inventory".to_string());
+    fn repair(&mut self, slot: Slot) -> Vec<Event> {
+        let Some(item) = self.player.equipment.get(slot) else {
+            return vec![Event::NoSuchItem];
+        };
+        let name = item.name().to_string();
+        let price = self.store.repair_price(item);
 
-        let inventory_selection = self.get_selection("Equip", &shield_names);
+        if price == 0 {
+            return vec![Event::NothingToRepair { name }];
+        }
+        if self.player.money < price {
+            return vec![Event::CannotAffordRepair {
+                name,
+                price,
+                money: self.player.money,
+            }];
+        }
 
-        if inventory_selection == shield_names.len() - 1 {
-            // The player chose the "Back" option
-            self.player_inventory();
-        } else {
-            let selected_shield = self.player.inventory.shields[inventory_selection].clone();
-            if self.player.strength < selected_shield.req_strength {
-                slow_type(format!("Can not equip: {:?}", selected_shield).as_str());
-                slow_type(
-                    format!(
-                        "Strength Required: {} Current Strength {}",
-                        selected_shield.req_strength, self.player.strength
-                    )
-                    .as_str(),
-                );
-            } else {
-                slow_type(format!("Equipping: {:?}", selected_shield).as_str());
-                self.player
-                    .equipment
-                    .equip_shield(selected_shield.to_owned());
-            }
+        self.player.money -= price;
+        if let Some(item) = self.player.equipment.get_mut(slot) {
+            item.durability_mut().repair();
         }
+        vec![Event::Repaired { name, price }]
     }
 
-    fn player_inventory_equip_helmet(&mut self) {
-        let mut helmet_names = self.player.inventory.get_helmet_names();
-        helmet_names.push("Back to Inventory".to_string());
+    fn skip_fight(&mut self) -> Vec<Event> {
+        if !self.is_fight_week() {
+            return vec![Event::NotFightWeek];
+        }
+        let fame = self.player.fame;
+        let fame_events = self.change_fame(-SKIP_FIGHT_FAME_LOSS);
+        let mut events = vec![Event::FightSkipped {
+            fame_lost: fame - self.player.fame,
+        }];
+        events.extend(fame_events);
+        events.extend(self.advance_time());
+        events
+    }
 
-        let inventory_selection = self.get_selection("Equip", &helmet_names);
+    fn start_fight(&mut self) -> Vec<Event> {
+        let Some(games) = self.calendar.games() else {
+            return vec![Event::NotFightWeek];
+        };
+        if self.player.is_injured() {
+            let mut events = vec![Event::InjuredCannotFight];
+            events.extend(self.skip_fight());
+            return events;
+        }
+        let health_percentage = self.player.health as f32 / self.player.max_health() as f32;
+        if health_percentage <= 0.5 {
+            let mut events = vec![Event::NotReadyToFight];
+            events.extend(self.skip_fight());
+            return events;
+        }
 
-        if inventory_selection == helmet_names.len() - 1 {
-            // The player chose the "Back" option
-            self.player_inventory();
-        } else {
-            let selected_armor = self.player.inventory.helmets[inventory_selection].clone();
-            if self.player.strength < selected_armor.req_strength {
-                slow_type(format!("Can not equip: {}", helmet_names[inventory_selection]).as_str());
-                slow_type(
-                    format!(
-                        "Strength Required: {} Current Strength {}",
-                        selected_armor.req_strength, self.player.strength
-                    )
-                    .as_str(),
-                );
-            } else {
-                slow_type(format!("Equipping: {}", helmet_names[inventory_selection]).as_str());
-                self.player
-                    .equipment
-                    .equip_armor(self.player.inventory.helmets[inventory_selection].clone());
+        let enemy = Enemy::generate(&mut self.rng, self.player.fame, self.player.victories);
+        let mood = CrowdMood::roll(&mut self.rng);
+        let event = Event::FightStarted {
+            enemy: enemy.name.clone(),
+            behavior: enemy.behavior,
+            mood,
+            games,
+        };
+        self.phase = Phase::Fighting(Fight::new(enemy, mood));
+        vec![event]
+    }
+
+    fn fight_turn(&mut self, player_action: FightAction) -> Vec<Event> {
+        let Phase::Fighting(fight) = &mut self.phase else {
+            return vec![Event::CommandUnavailable];
+        };
+        let enemy = &mut fight.enemy;
+        let rng = &mut self.rng;
+        let mut events = vec![Event::PlayerAction(player_action)];
+        fight.turns += 1;
+
+        let player_health = self.player.health as f32 / self.player.max_health() as f32;
+        let player_tiredness = self.player.tiredness();
+        let enemy_action = enemy.choose_action(rng, player_health, player_tiredness);
+        // Stats are taken before the turn's exertion, like the enemy's
+        let player_stats = self.player.combat_stats();
+        let enemy_stats = enemy.combat_stats();
+        self.player.exert(player_action);
+        enemy.tire(enemy_action);
+
+        let mut player_strike = None;
+        let mut enemy_strike = None;
+        let mut worn = Vec::new();
+        if player_action == FightAction::Attack {
+            let strike = resolve_strike(rng, &player_stats, &enemy_stats, enemy_action);
+            player_strike = Some(strike);
+            if let Strike::Hit { damage, .. } = strike {
+                enemy.take_damage(damage);
+                fight.damage_dealt += damage;
+                worn.extend(self.player.equipment.wear(Slot::MainHand, WEAPON_WEAR));
             }
+            events.push(Event::PlayerStrike {
+                strike,
+                enemy_action,
+            });
         }
-    }
 
-    fn skip_fight(&mut self) {
-        slow_type("You choose to skip this fight!");
-        slow_type("Your lanista is not happy...");
-        slow_type("Your are losing fame.");
-        self.player.fame -= 10;
-        //TODO logic for fame loss
-        self.advance_time();
-    }
-
-    fn ludus_menu(&mut self) {
-        print_line();
-        println!("Week: {}", self.current_week);
-        if self.is_fight_week {
-            println!("This is a FIGHT week");
-            let options = &[
-                "Fight",
-                "Skip fight",
-                "Player Info",
-                "Inventory",
-                "Store",
-                "Save Game",
-                "To Main Menu",
-            ];
-            let selection = Select::with_theme(&ColorfulTheme::default())
-                .with_prompt("Ludus")
-                .default(0)
-                .items(&options[..])
-                .interact()
-                .unwrap();
-            match selection {
-                0 => {
-                    if self.player.injured {
-                        slow_type("You are injured and can't fight this week.");
-                        self.skip_fight();
+        if enemy.health > 0 {
+            events.push(Event::EnemyAction(enemy_action));
+            if enemy_action == FightAction::Attack {
+                let strike = resolve_strike(rng, &enemy_stats, &player_stats, player_action);
+                enemy_strike = Some(strike);
+                events.push(Event::EnemyStrike(strike));
+                if let Strike::Hit {
+                    damage, location, ..
+                } = strike
+                {
+                    self.player.take_damage(damage);
+                    fight.damage_taken += damage;
+                    let equipment = &mut self.player.equipment;
+                    if player_action == FightAction::Block {
+                        worn.extend(equipment.wear(Slot::OffHand, SHIELD_WEAR));
                     }
-                    let health_percentage =
-                        self.player.health as f32 / self.player.max_health as f32;
-                    if health_percentage > 0.5 {
-                        self.fight();
-                    } else {
-                        slow_type("You are not ready to fight. Your health is to low.");
-                        self.skip_fight();
+                    for armor_type in ArmorType::ALL {
+                        if armor_type.covers() == location {
+                            worn.extend(equipment.wear(Slot::Armor(armor_type), ARMOR_WEAR));
+                        }
+                    }
+                    let player = &self.player;
+                    if let Some(injury) = Injury::roll(
+                        rng,
+                        damage,
+                        location,
+                        player.max_health(),
+                        player.agility,
+                        player.tiredness(),
+                    ) {
+                        self.player.injuries.push(injury.clone());
+                        events.push(Event::Injured { injury });
                     }
                 }
-                1 => self.skip_fight(),
-                2 => self.player_info(),
-                3 => self.player_inventory(),
-                4 => self.store_menu(),
-                5 => {
-                    self.save_game("save1.json").expect("Failed to save game."); // TODO: add different save files
-                    slow_type("Game saved.");
-                    self.ludus_menu();
-                }
-                6 => self.state = GameState::MainMenu,
-                _ => unreachable!(),
-            }
-        } else {
-            let options = &[
-                "Player Info",
-                "Train",
-                "Rest",
-                "Inventory",
-                "Store",
-                "Buy Freedom",
-                "Save Game",
-                "To Main Menu",
-            ];
-            let selection = Select::with_theme(&ColorfulTheme::default())
-                .with_prompt("Ludus")
-                .default(0)
-                .items(&options[..])
-                .interact()
-                .unwrap();
-
-            match selection {
-                0 => self.player_info(),
-                1 => self.train(),
-                2 => self.rest(),
-                3 => self.player_inventory(),
-                4 => self.store_menu(),
-                5 => self.buy_freedom(),
-                6 => {
-                    self.save_game("save1.json").expect("Failed to save game.");
-                    slow_type("Game saved.");
-                    self.ludus_menu();
-                }
-                7 => self.state = GameState::MainMenu,
-                _ => unreachable!(),
             }
         }
+
+        events.extend(worn.into_iter().map(|name| Event::ItemBroken { name }));
+
+        let player_health = self.player.health as f32 / self.player.max_health() as f32;
+        fight.excite(player_action, player_strike, enemy_strike, player_health);
+
+        if fight.escape && self.player.health <= 0 {
+            self.phase = Phase::Over(Ending::Death);
+            events.push(Event::RevoltCrushed);
+        } else if fight.escape && fight.enemy.health <= 0 {
+            self.phase = Phase::Over(Ending::Escape);
+            events.push(Event::Escaped);
+        } else if self.player.health <= 0 {
+            let Phase::Fighting(fight) = std::mem::replace(&mut self.phase, Phase::Ludus) else {
+                unreachable!()
+            };
+            self.phase = Phase::Defeated(fight);
+            events.push(Event::Defeated);
+        } else if fight.enemy.health <= 0 {
+            let chance = fight
+                .crowd_mood
+                .enemy_missio_chance(fight.enemy.fame, fight.spectacle());
+            let crowd_wants_mercy = rng.gen_bool(chance);
+            let Phase::Fighting(fight) = std::mem::replace(&mut self.phase, Phase::Ludus) else {
+                unreachable!()
+            };
+            self.phase = Phase::Verdict {
+                fight,
+                crowd_wants_mercy,
+            };
+            events.push(Event::EnemyDefeated { crowd_wants_mercy });
+        }
+        events
     }
 
-    fn train(&mut self) {
-        //TODO: add tiredness
-        //TODO: add skill increase
-        //TODO: add special moves
-        if self.player.injured {
-            slow_type("You can't train because of your injury... Try resting first.");
-            self.state = GameState::InGame;
+    /// Spares or kills the defeated opponent and collects the victory.
+    /// The crowd and the lanista reward following the crowd's wish,
+    /// the other gladiators respect clemency.
+    fn verdict(&mut self, spare: bool) -> Vec<Event> {
+        let Phase::Verdict {
+            fight,
+            crowd_wants_mercy,
+        } = std::mem::replace(&mut self.phase, Phase::Ludus)
+        else {
+            unreachable!()
+        };
+        let obeyed_crowd = spare == crowd_wants_mercy;
+        let mut events = vec![if spare {
+            Event::EnemySpared { obeyed_crowd }
         } else {
-            slow_type("You are training...");
-            self.player.strength += 1;
-            self.advance_time();
-        }
-    }
-
-    fn rest(&mut self) {
-        // TODO: figure out heal amout and injury heal process
-        slow_type("You are resting. Restored 5 health");
-        self.player.heal(5);
-        println!("{}", self.player.health_bar());
-        self.advance_time();
-        self.state = GameState::InGame; //TODO: maybe add it to advance_time()
-    }
-
-    fn new_game(&mut self) {
-        // TODO: add backstory of prisor of war
-        // TODO: add skills setup during new game like
-        clear_screen();
-        slow_type("INTRODUCTION...");
-        let text = "You found yourself in the arena...with a rusty sword in your hand and a terrifying enemy in front of you";
-        slow_type(text);
-        self.state = GameState::InGame;
-        self.player.heal(self.player.max_health);
-        self.fight();
-    }
-
-    fn end_game(&mut self) {
-        slow_type(" ");
-        slow_type("GAME OVER...");
-        slow_type(" ");
-        slow_type(" ");
-        self.state = GameState::Exit;
-    }
-
-    fn beg_for_mercy(&mut self) {
-        let mercy_option = &["Yes", "No"];
-
-        let mercy_selection = Select::with_theme(&ColorfulTheme::default())
-            .with_prompt("Beg For Mercy?")
-            .default(0)
-            .items(&mercy_option[..])
-            .interact()
-            .unwrap();
-
-        match mercy_selection {
-            0 => self.show_mercy(),
-            1 => {
-                slow_type("Defeated yet defiant, you lie wounded in the Colosseum's arena, refusing to plead for mercy. Your pride remains unbroken, even in the face of imminent death.");
-                slow_type("You feel the cold, sharp sting of your enemy's weapon and everything turns black...");
-                self.state = GameState::GameOver;
-            }
-            _ => unreachable!(),
+            Event::EnemyKilled { obeyed_crowd }
+        }];
+
+        let relationships = &mut self.player.relationships;
+        relationships.lanista += if obeyed_crowd {
+            VERDICT_RELATIONSHIP
+        } else {
+            -VERDICT_RELATIONSHIP
+        };
+        relationships.gladiators += if spare {
+            VERDICT_RELATIONSHIP
+        } else {
+            -VERDICT_RELATIONSHIP
+        };
+
+        // The purse is the opponent's coin, the lanista keeps a share of it
+        let lanista_cut = Spoils::lanista_cut(self.player.relationships.lanista);
+        let purse = self.player.fame_level.purse(fight.enemy.money);
+        let lanista_share = purse * lanista_cut / 100;
+        let mut fame = VICTORY_FAME + fight.enemy.fame as i32 / 2 + fight.spectacle() as i32 / 10;
+        fame += if obeyed_crowd {
+            VERDICT_FAME
+        } else {
+            -VERDICT_FAME
+        };
+        let crowd_bonus = fight.crowd_bonus(purse);
+        // Fights in older saves may fall on a week without games
+        let festival_bonus = self
+            .calendar
+            .games()
+            .map_or(0, |games| purse * games.purse_bonus() / 100);
+        self.player.money += purse - lanista_share + crowd_bonus + festival_bonus;
+        events.push(Event::Victory {
+            purse,
+            lanista_share,
+            crowd_bonus,
+            festival_bonus,
+            first: self.player.victories == 0,
+        });
+        self.player.victories += 1;
+        events.extend(self.change_fame(fame));
+        events.extend(self.share_spoils(&fight, lanista_cut));
+        events.extend(self.advance_time());
+        events
+    }
+
+    /// The lanista takes the same cut of the beaten opponent's gear as of the purse.
+    fn share_spoils(&mut self, fight: &Fight, lanista_cut: i32) -> Vec<Event> {
+        let spoils = Spoils::new(&mut self.rng, &fight.enemy, lanista_cut);
+        let event = Event::SpoilsOffered {
+            claims: spoils.claims,
+        };
+        if !spoils.items.is_empty() {
+            self.phase = Phase::Spoils(spoils);
+        }
+        vec![event]
+    }
+
+    fn claim(&mut self, index: usize) -> Vec<Event> {
+        let Phase::Spoils(spoils) = &mut self.phase else {
+            return vec![Event::CommandUnavailable];
+        };
+        let Some(item) = spoils.claim(index) else {
+            return vec![Event::NoSuchItem];
+        };
+        let mut events = vec![Event::SpoilClaimed {
+            name: item.name().to_string(),
+        }];
+        self.player.inventory.add_item(item);
+        if spoils.claims == 0 || spoils.items.is_empty() {
+            events.extend(self.leave_spoils());
         }
+        events
+    }
+
+    fn leave_spoils(&mut self) -> Vec<Event> {
+        let Phase::Spoils(spoils) = std::mem::replace(&mut self.phase, Phase::Ludus) else {
+            return vec![Event::CommandUnavailable];
+        };
+        vec![Event::SpoilsLeft {
+            sold: spoils.items.len(),
+        }]
     }
 
-    fn show_mercy(&mut self) {
-        // Embrace this new life with the strength of a warrior and the humility of one who has been given a second chance. Fight well, fight with honor, and let each victory bring you closer to the glory that now beckons.
-        slow_type("You raising your trembling hands in a desperate plea for mercy. Your eyes, filled with a mix of fear and resignation, scan the sea of faces in the crowded stands, searching for a hint of compassion. ");
-        let is_successfull = rand::thread_rng().gen_bool(0.5); //TODO: update chance based on popularity/fame
+    fn beg_for_mercy(&mut self) -> Vec<Event> {
+        let Phase::Defeated(fight) = &self.phase else {
+            return vec![Event::CommandUnavailable];
+        };
+        let chance = fight
+            .crowd_mood
+            .missio_chance(self.player.fame_level, fight.spectacle());
+        let is_successfull = self.rng.gen_bool(chance);
         if is_successfull {
-            slow_type("To your surprise, amidst the tumult, a wave of compassion seems to sweep over the spectators, and they signal for mercy, sparing your life. With a heavy heart and a sense of shame, you slowly rise and exit the Colosseum, alive but forever marked by the day the crowd chose to let you live.");
-            self.state = GameState::InGame;
+            self.phase = Phase::Ludus;
+            let mut events = vec![Event::MercyGranted];
+            // Nobody walks away unhurt from a lost fight
+            let location = Injury::roll_location(&mut self.rng);
+            let injury = Injury::new(location, Severity::Serious);
+            self.player.injuries.push(injury.clone());
+            events.push(Event::Injured { injury });
+            events.extend(self.change_fame(-MERCY_FAME_LOSS));
+            events.extend(self.advance_time());
+            events
         } else {
-            slow_type("Your gaze, filled with fear and hope, meets a sea of unyielding faces, their chants for violence drowning out your plea.");
-            slow_type("In that heart-wrenching moment, as the crowd's rejection seals your fate, you feel the cold, sharp sting of your enemy's weapon, ending your desperate fight for survival.");
-            self.state = GameState::GameOver;
-        }
-    }
-
-    fn fight(&mut self) {
-        let mut enemy: Player = Player::new("Enemy1".to_string()); //TODO select enemy for each fight
-        while self.player.health > 0 && enemy.health > 0 {
-            clear_screen();
-            println!("\t{} \t\t \t{}", self.player.name, enemy.name);
-            println!("{}\t{}\n", self.player.health_bar(), enemy.health_bar());
-            let action_options = &["Attack", "Block", "Wait"];
-            let fight_selection = Select::with_theme(&ColorfulTheme::default())
-                .with_prompt("Choose your attack target:")
-                .default(0)
-                .items(&action_options[..])
-                .interact()
-                .unwrap();
-
-            match fight_selection {
-                0 => {
-                    slow_type("Attacking...");
-                    // TODO: Implement the logic for attacking
-                    enemy.health -= 50;
-                }
-                1 => {
-                    slow_type("Blocking...");
-                    enemy.health -= 25;
-                    // TODO: Implement the logic for blocking
-                }
-                2 => {
-                    slow_type("Waiting...");
-                    enemy.health -= 1;
-                    // TODO: Implement the logic for waiting
-                }
-                _ => unreachable!(),
-            }
+            self.phase = Phase::Over(Ending::Death);
+            vec![Event::Killed { begged: true }]
+        }
+    }
 
-            // TODO: enemy attack logic
-            slow_type("Enemy attacks back!");
-            self.player.take_damage(25); // Example damage from the enemy
+    fn buy_freedom(&mut self) -> Vec<Event> {
+        if self.player.money >= FREEDOM_PRICE {
+            self.phase = Phase::Over(Ending::Freedom);
+            vec![Event::FreedomBought]
+        } else {
+            vec![Event::FreedomRefused {
+                price: FREEDOM_PRICE,
+                money: self.player.money,
+            }]
+        }
+    }
 
-            // Check if the player or enemy has been defeated
-            if self.player.health <= 0 {
-                self.beg_for_mercy();
-                break;
-            } else if enemy.health <= 0 {
-                slow_type("Your decisive blow having vanquished your formidable enemy");
-                slow_type("The crowd erupts in cheers, celebrating your triumph  as you emerge as the undisputed champion of the arena");
-
-                self.player.money += 10; //TODO use enemy struct
-                if self.player.victories == 0 {
-                    // first victory
-                    clear_screen();
-                    slow_type("You are led out of the arena, not as a mere prisoner of war or a slave bound by chains, but as a warrior who has proven his mettle in the heat of combat.");
-                    slow_type("The man in a silk cloth and two body guards approach you...");
-                    slow_type("");
-                    slow_type("[LANISTA] - You fought well today, beyond what was expected for a first fight.  Your victory is just the start.");
-                    slow_type("He pauses, ensuring his words sink in before continuing.");
-                    slow_type("[LANISTA] - Train hard, fight harder. Remember, you're here because I chose you—I see the gladiator in you. Your past is irrelevant; your future in the arena is what matters now.");
-                    slow_type("The lanista's gaze hardens");
-                    slow_type("[LANISTA] - I see in you a fighter worth the investment - prove me right, fight well and you will be rewarded.");
-                    slow_type("Lanista leaves...Two of his bodyguards excort you to the Ludus.");
-                }
-                self.player.victories += 1;
-                self.state = GameState::InGame;
-                break;
-            }
+    /// Plotting happens in the quiet weeks, when the guards are not on edge for the games.
+    fn plotting_allowed(&self, stage: Stage) -> bool {
+        self.rebellion.stage == stage && !self.is_fight_week()
+    }
+
+    fn recruit(&mut self) -> Vec<Event> {
+        if !self.plotting_allowed(Stage::Recruiting) {
+            return vec![Event::CommandUnavailable];
+        }
+        let respect = self.player.relationships.gladiators;
+        let mut events = if self.rebellion.recruit(&mut self.rng, respect) {
+            vec![Event::Recruited {
+                recruits: self.rebellion.recruits,
+                needed: RECRUITS_NEEDED,
+            }]
+        } else {
+            vec![Event::RecruitRefused]
+        };
+        events.extend(self.roll_discovery());
+        events.extend(self.advance_time());
+        events
+    }
+
+    fn prepare_revolt(&mut self) -> Vec<Event> {
+        if !self.plotting_allowed(Stage::Preparing) {
+            return vec![Event::CommandUnavailable];
+        }
+        let progress = self.rebellion.prepare(&mut self.rng);
+        let mut events = vec![Event::RevoltPrepared {
+            progress,
+            preparation: self.rebellion.preparation,
+        }];
+        events.extend(self.roll_discovery());
+        events.extend(self.advance_time());
+        events
+    }
+
+    /// Every week of plotting the lanista may hear of it. The player is
+    /// flogged and the recruits sold off, the plot has to start over.
+    fn roll_discovery(&mut self) -> Vec<Event> {
+        let chance = self
+            .rebellion
+            .discovery_chance(self.player.relationships.lanista);
+        if !self.rng.gen_bool(chance) {
+            return Vec::new();
+        }
+        let recruits_lost = self.rebellion.discovered();
+        self.player.health = (self.player.health - FLOGGING_DAMAGE).max(1);
+        self.player.relationships.lanista -= DISCOVERY_FAVOR_LOSS;
+        let injury = Injury::new(BodyPart::Torso, Severity::Light);
+        self.player.injuries.push(injury.clone());
+        vec![
+            Event::PlotDiscovered { recruits_lost },
+            Event::Injured { injury },
+        ]
+    }
+
+    fn revolt(&mut self) -> Vec<Event> {
+        if !self.plotting_allowed(Stage::Ready) {
+            return vec![Event::CommandUnavailable];
+        }
+        if self.player.is_injured() {
+            return vec![Event::InjuredCannotFight];
         }
-        self.advance_time();
-        clear_screen();
+        let captain = Enemy::guard_captain(
+            &mut self.rng,
+            self.player.fame,
+            self.player.victories,
+            self.rebellion.recruits,
+        );
+        let event = Event::RevoltStarted {
+            enemy: captain.name.clone(),
+            recruits: self.rebellion.recruits,
+        };
+        self.phase = Phase::Fighting(Fight::escape(captain));
+        vec![event]
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::character::Attribute;
 
-    fn load_game_menu(&mut self) {
-        let save_options = &["Save 1", "Save 2", "Save 3", "Back to Main Menu"];
+    // Wins its first fight when attacking every turn
+    const SEED: u64 = 2;
+    const REGIMEN: Regimen = Regimen {
+        attribute: Attribute::Strength,
+        doctore: false,
+    };
+
+    fn game(seed: u64) -> Game {
+        Game::with_seed(&Character::new("Spartacus".to_string()), seed)
+    }
 
-        let save_selection = Select::with_theme(&ColorfulTheme::default())
-            .with_prompt("Load Game")
-            .default(0)
-            .items(&save_options[..])
-            .interact()
-            .unwrap();
+    fn inventory_size(game: &Game) -> usize {
+        Slot::ALL
+            .iter()
+            .map(|slot| game.player.inventory.items(*slot).count())
+            .sum()
+    }
 
-        match save_selection {
-            0 => {
-                slow_type("Loading Save 1...");
-                *self = Game::load_game("save1.json").unwrap_or_else(|_| {
-                    slow_type("Failed to load game.");
-                    Game::new()
-                });
+    /// Attacks until the fight is over, or gives up after a hundred turns.
+    fn fight(game: &mut Game) -> Vec<Event> {
+        let mut events = game.execute(Command::StartFight);
+        for _ in 0..100 {
+            if !matches!(game.phase, Phase::Fighting(_)) {
+                break;
             }
-            1 => slow_type("Loading Save 2..."),
-            2 => slow_type("Loading Save 3..."),
-            3 => self.main_menu(),
-            _ => unreachable!(),
+            events.extend(game.execute(Command::Fight(FightAction::Attack)));
         }
+        events
     }
 
-    fn save_game(&self, filename: &str) -> io::Result<()> {
-        let json = serde_json::to_string(self)?;
-        fs::write(filename, json)?;
-        Ok(())
+    #[test]
+    fn a_won_fight_goes_through_the_verdict_and_the_spoils() {
+        let mut game = game(SEED);
+        fight(&mut game);
+        assert!(matches!(game.phase, Phase::Verdict { .. }));
+
+        // The purse is worked out from the opponent's coin, not from the event
+        let Phase::Verdict {
+            fight,
+            crowd_wants_mercy,
+        } = &game.phase
+        else {
+            unreachable!()
+        };
+        assert!(fight.enemy.money > 0);
+        let purse = game.player.fame_level.purse(fight.enemy.money);
+        let favor = game.player.relationships.lanista
+            + if *crowd_wants_mercy {
+                VERDICT_RELATIONSHIP
+            } else {
+                -VERDICT_RELATIONSHIP
+            };
+        let lanista_share = purse * Spoils::lanista_cut(favor) / 100;
+        let crowd_bonus = fight.crowd_bonus(purse);
+        let festival_bonus = game
+            .calendar
+            .games()
+            .map_or(0, |games| purse * games.purse_bonus() / 100);
+        let paid = purse - lanista_share + crowd_bonus + festival_bonus;
+
+        let money = game.player.money;
+        let events = game.execute(Command::SpareEnemy);
+        assert!(events.contains(&Event::Victory {
+            purse,
+            lanista_share,
+            crowd_bonus,
+            festival_bonus,
+            first: true,
+        }));
+        assert_eq!(game.player.money, money + paid);
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, Event::SpoilsOffered { claims } if *claims > 0)));
+        let Phase::Spoils(spoils) = &game.phase else {
+            panic!("no spoils after the verdict: {:?}", game.phase);
+        };
+        let (offered, claims) = (spoils.items.len(), spoils.claims);
+
+        // The last claim leaves the rest to the lanista
+        let carried = inventory_size(&game);
+        let mut events = Vec::new();
+        for _ in 0..claims {
+            events.extend(game.execute(Command::Claim(0)));
+        }
+        let claimed = events
+            .iter()
+            .filter(|event| matches!(event, Event::SpoilClaimed { .. }))
+            .count();
+        assert_eq!(claimed, claims);
+        assert_eq!(inventory_size(&game), carried + claims);
+        assert_eq!(
+            events.last(),
+            Some(&Event::SpoilsLeft {
+                sold: offered - claims
+            })
+        );
+        assert!(matches!(game.phase, Phase::Ludus));
+        assert_eq!(
+            game.execute(Command::LeaveSpoils),
+            vec![Event::CommandUnavailable]
+        );
+        assert_eq!(game.player.money, money + paid);
     }
 
-    fn load_game(filename: &str) -> io::Result<Self> {
-        let mut file = File::open(filename)?;
-        let mut contents = String::new();
-        file.read_to_string(&mut contents)?;
-        let game = serde_json::from_str(&contents)?;
-        Ok(game)
+    #[test]
+    fn commands_are_rejected_outside_their_phase() {
+        let mut game = game(SEED);
+        // Every story starts with the games
+        assert!(game.is_fight_week());
+        for command in [
+            Command::Train(REGIMEN),
+            Command::Rest,
+            Command::BuyFreedom,
+            Command::Recruit,
+            Command::PrepareRevolt,
+            Command::Revolt,
+        ] {
+            assert_eq!(
+                game.execute(command.clone()),
+                vec![Event::CommandUnavailable],
+                "{:?} in a games week",
+                command
+            );
+        }
+        assert_eq!(game.current_week(), 0);
+
+        for command in [
+            Command::Fight(FightAction::Attack),
+            Command::BegForMercy,
+            Command::SpareEnemy,
+            Command::Claim(0),
+            Command::LeaveSpoils,
+        ] {
+            assert_eq!(
+                game.execute(command.clone()),
+                vec![Event::CommandUnavailable],
+                "{:?} in the ludus",
+                command
+            );
+        }
+
+        game.execute(Command::StartFight);
+        for command in [Command::Rest, Command::StartFight, Command::KillEnemy] {
+            assert_eq!(
+                game.execute(command.clone()),
+                vec![Event::CommandUnavailable],
+                "{:?} in a fight",
+                command
+            );
+        }
+        assert!(matches!(game.phase, Phase::Fighting(_)));
+    }
+
+    #[test]
+    fn the_same_seed_and_commands_give_the_same_events() {
+        let play = |seed: u64| {
+            let mut game = game(seed);
+            let mut events = fight(&mut game);
+            events.extend(game.execute(Command::KillEnemy));
+            events.extend(game.execute(Command::Claim(0)));
+            events.extend(game.execute(Command::LeaveSpoils));
+            events.extend(game.execute(Command::Train(REGIMEN)));
+            events.extend(game.execute(Command::Rest));
+            events
+        };
+        for seed in [SEED, 7, 42] {
+            assert_eq!(play(seed), play(seed), "seed {}", seed);
+        }
     }
 }
@@ -0,0 +1,212 @@
+use super::combat::MAX_TIREDNESS;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// Chance of an injury per point of damage, as a share of max health
+const INJURY_CHANCE_PER_DAMAGE: f64 = 3.0;
+// Every point of agility takes this much off the injury chance
+const AGILITY_PROTECTION: f64 = 0.01;
+// An exhausted fighter is this much more likely to get hurt
+const EXHAUSTION_RISK: f64 = 0.15;
+const MAX_INJURY_CHANCE: f64 = 0.5;
+// Share of max health a single blow has to take for worse injuries
+const SERIOUS_DAMAGE: f32 = 0.2;
+const CRITICAL_DAMAGE: f32 = 0.35;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BodyPart {
+    Head,
+    Torso,
+    Arm,
+    Leg,
+}
+
+impl fmt::Display for BodyPart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BodyPart::Head => write!(f, "head"),
+            BodyPart::Torso => write!(f, "torso"),
+            BodyPart::Arm => write!(f, "arm"),
+            BodyPart::Leg => write!(f, "leg"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Severity {
+    Light,
+    Serious,
+    Critical,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Severity::Light => write!(f, "light"),
+            Severity::Serious => write!(f, "serious"),
+            Severity::Critical => write!(f, "critical"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum InjuryKind {
+    Cut,
+    Concussion,
+    BrokenRibs,
+    BrokenArm,
+    Sprain,
+}
+
+impl fmt::Display for InjuryKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InjuryKind::Cut => write!(f, "Cut"),
+            InjuryKind::Concussion => write!(f, "Concussion"),
+            InjuryKind::BrokenRibs => write!(f, "Broken ribs"),
+            InjuryKind::BrokenArm => write!(f, "Broken arm"),
+            InjuryKind::Sprain => write!(f, "Sprain"),
+        }
+    }
+}
+
+/// Attribute points an injury takes away while it heals.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Penalties {
+    pub strength: u8,
+    pub agility: u8,
+    pub defense: u8,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct Injury {
+    pub kind: InjuryKind,
+    pub location: BodyPart,
+    pub severity: Severity,
+    pub weeks_left: u32,
+}
+
+impl fmt::Display for Injury {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}, {}), {} week(s) left",
+            self.kind, self.location, self.severity, self.weeks_left
+        )
+    }
+}
+
+impl Injury {
+    /// Light wounds are cuts anywhere, worse ones depend on where the blow landed.
+    pub fn new(location: BodyPart, severity: Severity) -> Self {
+        let kind = match (severity, location) {
+            (Severity::Light, _) => InjuryKind::Cut,
+            (_, BodyPart::Head) => InjuryKind::Concussion,
+            (_, BodyPart::Torso) => InjuryKind::BrokenRibs,
+            (_, BodyPart::Arm) => InjuryKind::BrokenArm,
+            (_, BodyPart::Leg) => InjuryKind::Sprain,
+        };
+        let weeks_left = match severity {
+            Severity::Light => 1,
+            Severity::Serious => 3,
+            Severity::Critical => 6,
+        };
+        Injury {
+            kind,
+            location,
+            severity,
+            weeks_left,
+        }
+    }
+
+    /// Rolls whether a blow of `damage` to `location` injures a fighter,
+    /// nimble ones are better at rolling with the blow and tired ones worse.
+    pub fn roll<R: Rng>(
+        rng: &mut R,
+        damage: i32,
+        location: BodyPart,
+        max_health: i32,
+        agility: u8,
+        tiredness: u8,
+    ) -> Option<Self> {
+        let share = damage.max(0) as f32 / max_health.max(1) as f32;
+        let chance = (share as f64 * INJURY_CHANCE_PER_DAMAGE
+            - agility as f64 * AGILITY_PROTECTION
+            + tiredness.min(MAX_TIREDNESS) as f64 / MAX_TIREDNESS as f64 * EXHAUSTION_RISK)
+            .clamp(0.0, MAX_INJURY_CHANCE);
+        if !rng.gen_bool(chance) {
+            return None;
+        }
+        let severity = if share >= CRITICAL_DAMAGE {
+            Severity::Critical
+        } else if share >= SERIOUS_DAMAGE {
+            Severity::Serious
+        } else {
+            Severity::Light
+        };
+        Some(Injury::new(location, severity))
+    }
+
+    pub fn roll_location<R: Rng>(rng: &mut R) -> BodyPart {
+        match rng.gen_range(0, 6) {
+            0 => BodyPart::Head,
+            1 | 2 => BodyPart::Torso,
+            3 | 4 => BodyPart::Arm,
+            _ => BodyPart::Leg,
+        }
+    }
+
+    /// Serious injuries keep a gladiator out of training and the arena.
+    pub fn is_disabling(&self) -> bool {
+        self.severity >= Severity::Serious
+    }
+
+    /// Critical injuries hurt twice as much as serious ones.
+    pub fn penalties(&self) -> Penalties {
+        let factor = match self.severity {
+            Severity::Light => 1,
+            Severity::Serious => 1,
+            Severity::Critical => 2,
+        };
+        let penalties = match self.kind {
+            InjuryKind::Cut => Penalties {
+                strength: 1,
+                ..Penalties::default()
+            },
+            InjuryKind::Concussion => Penalties {
+                agility: 3,
+                ..Penalties::default()
+            },
+            InjuryKind::BrokenRibs => Penalties {
+                strength: 1,
+                agility: 1,
+                defense: 1,
+            },
+            InjuryKind::BrokenArm => Penalties {
+                strength: 3,
+                ..Penalties::default()
+            },
+            InjuryKind::Sprain => Penalties {
+                agility: 3,
+                ..Penalties::default()
+            },
+        };
+        Penalties {
+            strength: penalties.strength * factor,
+            agility: penalties.agility * factor,
+            defense: penalties.defense * factor,
+        }
+    }
+
+    /// A broken arm can't hold a weapon that needs both hands.
+    pub fn blocks_two_handed(&self) -> bool {
+        self.kind == InjuryKind::BrokenArm
+    }
+
+    /// Some weeks closer to healing, true once healed.
+    pub fn heal(&mut self, weeks: u32) -> bool {
+        self.weeks_left = self.weeks_left.saturating_sub(weeks);
+        self.weeks_left == 0
+    }
+}
@@ -1,25 +1,24 @@
-use std::ops::Deref;
-
-use crate::models::items::item::Item;
+use crate::models::items::item::{Equipable, Item, Slot};
 use crate::models::items::weapon::Weapon;
-use crate::models::utils::print_line;
 
-use super::items::armor::{self, ArmorType};
-use super::items::hand_item::{HandItem, HandItemType};
+use super::items::armor::ArmorType;
+use super::items::hand_item::HandItemType;
 use super::items::{armor::Armor, shield::Shield};
-use super::items::{item, shield, weapon};
-use prettytable::{cell, row, Cell, Row, Table};
-use rand::seq::index;
+use super::utils::Table;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Identifies an item for as long as it stays in the same inventory.
+pub type ItemId = u32;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Inventory {
-    pub weapons: Vec<Weapon>,
-    pub shields: Vec<Shield>,
-    pub helmets: Vec<Armor>,
+    items: BTreeMap<ItemId, Item>,
+    next_id: ItemId,
 }
 
 impl Inventory {
+    /// The gear a new gladiator finds in the ludus.
     pub fn new() -> Self {
         let shield = Shield::new(
             "Basic Shield".to_string(),
@@ -47,93 +46,78 @@ impl Inventory {
             1,
             5,
         );
-        let mut weapons = Vec::new();
-        let mut shields = Vec::new();
-        let mut helmets = Vec::new();
-        helmets.push(helmet);
-        shields.push(shield);
-        shields.push(shield2);
-        weapons.push(weapon2);
-        //
 
-        Inventory {
-            weapons,
-            shields,
-            helmets,
-        }
+        Inventory::with_items(vec![
+            Item::Weapon(weapon2),
+            Item::Shield(shield),
+            Item::Shield(shield2),
+            Item::Armor(helmet),
+        ])
     }
 
-    // pub fn add_weapon(&mut self, w: Weapon) {
-    //     self.weapons.push(w);
-    // }
-
-    pub fn add_item(&mut self, item: Item) {
-        match item {
-            Item::Weapon(weapon) => self.weapons.push(weapon),
-            Item::Shield(shield) => self.shields.push(shield),
-            Item::Armor(armor) => match armor.armor_type {
-                ArmorType::Helmet => self.helmets.push(armor),
-                ArmorType::BreastPlate => todo!(),
-                ArmorType::Legs => todo!(),
-            },
+    pub fn with_items(items: Vec<Item>) -> Self {
+        let mut inventory = Inventory {
+            items: BTreeMap::new(),
+            next_id: 0,
+        };
+        for item in items {
+            inventory.add_item(item);
         }
+        inventory
     }
 
-    pub fn print_all_weapons(&self) {
-        let mut weapons_table = Table::new();
-
-        weapons_table.set_titles(row!["Name", "Type", "Damage", "Required Strength"]);
-
-        for weapon in &self.weapons {
-            weapons_table.add_row(weapon.to_row());
-        }
-        println!("Inventory");
-        print_line();
-        println!("Weapons");
-        weapons_table.printstd();
+    pub fn add_item(&mut self, item: Item) -> ItemId {
+        let id = self.next_id;
+        self.items.insert(id, item);
+        self.next_id += 1;
+        id
     }
 
-    pub fn print_all_helmets(&self) {
-        let mut armors_table = Table::new();
-        armors_table.set_titles(row![
-            "Name",
-            "Type",
-            "Defense",
-            "Weight",
-            "Required Strength",
-            "Price"
-        ]);
+    pub fn get(&self, id: ItemId) -> Option<&Item> {
+        self.items.get(&id)
+    }
 
-        for item in &self.helmets {
-            armors_table.add_row(item.to_row());
-        }
+    pub fn remove(&mut self, id: ItemId) -> Option<Item> {
+        self.items.remove(&id)
+    }
 
-        // Print the tables
-        println!("Inventory");
-        print_line();
-        println!("Armors");
-        armors_table.printstd();
+    /// Items for one slot, oldest first.
+    pub fn items(&self, slot: Slot) -> impl Iterator<Item = (ItemId, &Item)> {
+        self.items
+            .iter()
+            .filter(move |(_, item)| item.slot() == slot)
+            .map(|(id, item)| (*id, item))
     }
 
-    pub fn print_all_shields(&self) {
-        let mut shields_table = Table::new();
+    pub fn table(&self, slot: Slot) -> Table {
+        self.priced_table(slot, |price| price)
+    }
 
-        shields_table.set_titles(row![
+    /// Lists the items for a slot with prices adjusted by `price`.
+    pub fn priced_table(&self, slot: Slot, price: impl Fn(i32) -> i32) -> Table {
+        let mut table = Table::new(&[
             "Name",
-            "Type",
-            "Block Damage",
+            "Rarity",
+            "Slot",
+            "Stats",
             "Weight",
             "Required Strength",
-            "Price"
+            "Durability",
+            "Price",
         ]);
 
-        for shield in &self.shields {
-            shields_table.add_row(shield.to_row());
+        for (_, item) in self.items(slot) {
+            table.rows.push(vec![
+                item.name().to_string(),
+                item.rarity().to_string(),
+                item.slot().to_string(),
+                item.stats().to_string(),
+                item.weight().to_string(),
+                item.req_strength().to_string(),
+                item.durability().to_string(),
+                price(item.price()).to_string(),
+            ]);
         }
-        // Print the tables
-        println!("Inventory");
-        print_line();
-        println!("Shields");
-        shields_table.printstd();
+        table
     }
 }
@@ -1,24 +1,147 @@
-use prettytable::{cell, row, Row};
+use super::durability::Durability;
+use super::loot::{Affix, Rarity};
+use crate::models::injury::BodyPart;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ArmorType {
     Helmet,
-    // Shoulder,
-    // Gloves,
+    Shoulder,
+    Gloves,
     BreastPlate,
     Legs,
-    // Boots,
+    Boots,
 }
 
 impl fmt::Display for ArmorType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             ArmorType::Helmet => write!(f, "Helmet"),
-            ArmorType::BreastPlate => write!(f, "BreastPlate"),
+            ArmorType::Shoulder => write!(f, "Shoulders"),
+            ArmorType::Gloves => write!(f, "Gloves"),
+            ArmorType::BreastPlate => write!(f, "Breastplate"),
             ArmorType::Legs => write!(f, "Legs"),
+            ArmorType::Boots => write!(f, "Boots"),
+        }
+    }
+}
+
+impl ArmorType {
+    /// From head to toe.
+    pub const ALL: [ArmorType; 6] = [
+        ArmorType::Helmet,
+        ArmorType::Shoulder,
+        ArmorType::BreastPlate,
+        ArmorType::Gloves,
+        ArmorType::Legs,
+        ArmorType::Boots,
+    ];
+
+    /// The part of the body this piece protects.
+    pub fn covers(&self) -> BodyPart {
+        match self {
+            ArmorType::Helmet => BodyPart::Head,
+            ArmorType::BreastPlate => BodyPart::Torso,
+            ArmorType::Shoulder | ArmorType::Gloves => BodyPart::Arm,
+            ArmorType::Legs | ArmorType::Boots => BodyPart::Leg,
+        }
+    }
+}
+
+/// Armor defense over each part of the body.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Coverage {
+    pub head: u32,
+    pub torso: u32,
+    pub arms: u32,
+    pub legs: u32,
+}
+
+impl Coverage {
+    pub fn defense(&self, part: BodyPart) -> u32 {
+        match part {
+            BodyPart::Head => self.head,
+            BodyPart::Torso => self.torso,
+            BodyPart::Arm => self.arms,
+            BodyPart::Leg => self.legs,
+        }
+    }
+
+    fn add(&mut self, part: BodyPart, defense: u32) {
+        match part {
+            BodyPart::Head => self.head += defense,
+            BodyPart::Torso => self.torso += defense,
+            BodyPart::Arm => self.arms += defense,
+            BodyPart::Leg => self.legs += defense,
+        }
+    }
+}
+
+/// The armor a fighter wears, one piece per slot.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq, Clone)]
+pub struct ArmorSet {
+    pub helmet: Option<Armor>,
+    pub shoulders: Option<Armor>,
+    pub breastplate: Option<Armor>,
+    pub gloves: Option<Armor>,
+    pub legs: Option<Armor>,
+    pub boots: Option<Armor>,
+}
+
+impl ArmorSet {
+    pub fn get(&self, armor_type: ArmorType) -> Option<&Armor> {
+        match armor_type {
+            ArmorType::Helmet => self.helmet.as_ref(),
+            ArmorType::Shoulder => self.shoulders.as_ref(),
+            ArmorType::BreastPlate => self.breastplate.as_ref(),
+            ArmorType::Gloves => self.gloves.as_ref(),
+            ArmorType::Legs => self.legs.as_ref(),
+            ArmorType::Boots => self.boots.as_ref(),
+        }
+    }
+
+    pub fn get_mut(&mut self, armor_type: ArmorType) -> Option<&mut Armor> {
+        self.slot(armor_type).as_mut()
+    }
+
+    fn slot(&mut self, armor_type: ArmorType) -> &mut Option<Armor> {
+        match armor_type {
+            ArmorType::Helmet => &mut self.helmet,
+            ArmorType::Shoulder => &mut self.shoulders,
+            ArmorType::BreastPlate => &mut self.breastplate,
+            ArmorType::Gloves => &mut self.gloves,
+            ArmorType::Legs => &mut self.legs,
+            ArmorType::Boots => &mut self.boots,
+        }
+    }
+
+    /// Puts on a piece, returns the one it replaces.
+    pub fn equip(&mut self, armor: Armor) -> Option<Armor> {
+        self.slot(armor.armor_type).replace(armor)
+    }
+
+    pub fn take_off(&mut self, armor_type: ArmorType) -> Option<Armor> {
+        self.slot(armor_type).take()
+    }
+
+    pub fn pieces(&self) -> impl Iterator<Item = &Armor> {
+        ArmorType::ALL
+            .into_iter()
+            .filter_map(|armor_type| self.get(armor_type))
+    }
+
+    pub fn weight(&self) -> u32 {
+        self.pieces().map(|armor| armor.weight).sum()
+    }
+
+    pub fn coverage(&self) -> Coverage {
+        let mut coverage = Coverage::default();
+        for armor in self.pieces() {
+            let defense = armor.durability.apply(armor.defense);
+            coverage.add(armor.armor_type.covers(), defense as u32);
         }
+        coverage
     }
 }
 
@@ -30,6 +153,9 @@ pub struct Armor {
     pub req_strength: u8,
     pub price: u32,
     pub weight: u32,
+    pub durability: Durability,
+    pub rarity: Rarity,
+    pub affixes: Vec<Affix>,
 }
 
 impl fmt::Display for Armor {
@@ -54,17 +180,9 @@ impl Armor {
             req_strength,
             price,
             weight,
+            durability: Durability::default(),
+            rarity: Rarity::Common,
+            affixes: Vec::new(),
         }
     }
-
-    pub fn to_row(&self) -> Row {
-        row![
-            &self.name,
-            &self.armor_type,
-            &self.defense,
-            &self.weight,
-            &self.req_strength,
-            &self.price
-        ]
-    }
 }
@@ -0,0 +1,160 @@
+use super::armor::{Armor, ArmorType};
+use super::hand_item::HandItemType;
+use super::shield::Shield;
+use super::weapon::Weapon;
+
+// Every item that can be bought in the store or carried into the arena
+
+pub fn weapons() -> Vec<Weapon> {
+    vec![
+        Weapon::new("Rusty Dagger".to_string(), HandItemType::Single, 2, 5, 5, 5),
+        Weapon::new(
+            "Wooden Club".to_string(),
+            HandItemType::Single,
+            4,
+            8,
+            10,
+            200,
+        ),
+        Weapon::new(
+            "Short Sword".to_string(),
+            HandItemType::Single,
+            6,
+            10,
+            15,
+            350,
+        ),
+        Weapon::new(
+            "Battle Axe".to_string(),
+            HandItemType::Single,
+            8,
+            15,
+            20,
+            500,
+        ),
+        Weapon::new(
+            "War Hammer".to_string(),
+            HandItemType::Double,
+            10,
+            18,
+            25,
+            700,
+        ),
+        Weapon::new(
+            "Longsword".to_string(),
+            HandItemType::Single,
+            12,
+            20,
+            30,
+            900,
+        ),
+        Weapon::new("Flail".to_string(), HandItemType::Single, 14, 22, 35, 1100),
+        Weapon::new(
+            "Greatsword".to_string(),
+            HandItemType::Double,
+            16,
+            25,
+            40,
+            1350,
+        ),
+        Weapon::new(
+            "Halberd".to_string(),
+            HandItemType::Double,
+            18,
+            28,
+            45,
+            1600,
+        ),
+        Weapon::new(
+            "Mystic Staff".to_string(),
+            HandItemType::Single,
+            20,
+            30,
+            50,
+            2000,
+        ),
+    ]
+}
+
+pub fn shields() -> Vec<Shield> {
+    vec![
+        Shield::new("Buckler".to_string(), HandItemType::Single, 5, 5, 80, 8),
+        Shield::new("Parma".to_string(), HandItemType::Single, 10, 12, 250, 15),
+        Shield::new("Scutum".to_string(), HandItemType::Single, 18, 25, 600, 30),
+    ]
+}
+
+pub fn armors() -> Vec<Armor> {
+    vec![
+        Armor::new("Leather Cap".to_string(), ArmorType::Helmet, 2, 3, 60, 3),
+        Armor::new(
+            "Bronze Helmet".to_string(),
+            ArmorType::Helmet,
+            8,
+            18,
+            300,
+            8,
+        ),
+        Armor::new(
+            "Gladiator Helm".to_string(),
+            ArmorType::Helmet,
+            12,
+            30,
+            700,
+            10,
+        ),
+        Armor::new(
+            "Leather Vest".to_string(),
+            ArmorType::BreastPlate,
+            3,
+            5,
+            120,
+            8,
+        ),
+        Armor::new(
+            "Chainmail".to_string(),
+            ArmorType::BreastPlate,
+            8,
+            20,
+            450,
+            20,
+        ),
+        Armor::new(
+            "Lorica Segmentata".to_string(),
+            ArmorType::BreastPlate,
+            14,
+            35,
+            1000,
+            25,
+        ),
+        Armor::new(
+            "Leather Pauldron".to_string(),
+            ArmorType::Shoulder,
+            2,
+            5,
+            70,
+            3,
+        ),
+        Armor::new("Galerus".to_string(), ArmorType::Shoulder, 5, 15, 250, 6),
+        Armor::new("Leather Wraps".to_string(), ArmorType::Gloves, 1, 2, 40, 1),
+        Armor::new("Manica".to_string(), ArmorType::Gloves, 5, 15, 280, 6),
+        Armor::new("Leather Greaves".to_string(), ArmorType::Legs, 2, 5, 80, 4),
+        Armor::new(
+            "Bronze Greaves".to_string(),
+            ArmorType::Legs,
+            6,
+            20,
+            350,
+            10,
+        ),
+        Armor::new("Caligae".to_string(), ArmorType::Boots, 2, 3, 60, 2),
+        Armor::new(
+            "Hobnailed Boots".to_string(),
+            ArmorType::Boots,
+            4,
+            12,
+            200,
+            5,
+        ),
+    ]
+}
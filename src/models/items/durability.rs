@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+pub const MAX_DURABILITY: u8 = 100;
+// Below this much durability an item starts losing effectiveness...
+const WORN_DURABILITY: u8 = 40;
+// ...down to this share of it, in percent, right before it breaks
+const MIN_EFFECTIVENESS: u32 = 50;
+
+/// How much use an item can still take, from 100 (new) to 0 (broken).
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Durability(u8);
+
+impl Default for Durability {
+    fn default() -> Self {
+        Durability(MAX_DURABILITY)
+    }
+}
+
+impl fmt::Display for Durability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}%", self.0)
+    }
+}
+
+impl Durability {
+    /// Durability lost since the item was new.
+    pub fn missing(&self) -> u8 {
+        MAX_DURABILITY - self.0
+    }
+
+    pub fn is_broken(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn is_worn(&self) -> bool {
+        self.0 < WORN_DURABILITY
+    }
+
+    /// Wears the item down, returns whether it broke.
+    pub fn wear(&mut self, amount: u8) -> bool {
+        self.0 = self.0.saturating_sub(amount);
+        self.is_broken()
+    }
+
+    pub fn repair(&mut self) {
+        self.0 = MAX_DURABILITY;
+    }
+
+    /// Share of its stats a worn item still delivers, in percent.
+    pub fn effectiveness(&self) -> u32 {
+        if self.is_worn() {
+            MIN_EFFECTIVENESS + (100 - MIN_EFFECTIVENESS) * self.0 as u32 / WORN_DURABILITY as u32
+        } else {
+            100
+        }
+    }
+
+    /// Scales a stat of the item down to what it is still worth.
+    pub fn apply(&self, stat: u8) -> u8 {
+        (stat as u32 * self.effectiveness() / 100) as u8
+    }
+}
@@ -1,7 +1,8 @@
-use super::armor::Armor;
+use super::armor::{Armor, ArmorSet, ArmorType};
+use super::item::{Equipable, Item, Slot};
+use super::shield::Shield;
 use super::weapon::Weapon;
-use super::{armor::ArmorType, shield::Shield};
-use prettytable::{cell, row, Table};
+use crate::models::utils::Table;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -33,13 +34,6 @@ pub enum Hand {
     Single(HandItem),
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
-pub struct ArmorSet {
-    pub helmet: Option<Armor>,
-    pub breastplate: Option<Armor>,
-    pub boots: Option<Armor>,
-}
-
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 pub struct Equipment {
     pub left_hand: Hand,
@@ -60,128 +54,194 @@ impl Equipment {
         Equipment {
             right_hand: Hand::Single(HandItem::Weapon(weapon)),
             left_hand: Hand::Empty,
-            armor: ArmorSet {
-                helmet: None,
-                breastplate: None,
-                boots: None,
-            },
+            armor: ArmorSet::default(),
         }
     }
 
-    pub fn equip_weapon(&mut self, weapon: Weapon) {
+    /// Takes up a weapon, returns whatever the hands let go of.
+    pub fn equip_weapon(&mut self, weapon: Weapon) -> Vec<Item> {
+        let mut replaced = Vec::new();
         match weapon.item_type {
-            HandItemType::Single => self.right_hand = Hand::Single(HandItem::Weapon(weapon)),
-            HandItemType::Double => {
-                self.right_hand = Hand::Single(HandItem::Weapon(weapon));
-                self.left_hand = Hand::Busy; // Two-handed weapons occupy both hands
-            }
+            HandItemType::Single if self.left_hand == Hand::Busy => self.left_hand = Hand::Empty,
+            HandItemType::Single => {}
+            // Two-handed weapons occupy both hands
+            HandItemType::Double => replaced.extend(swap(&mut self.left_hand, Hand::Busy)),
+        }
+        replaced.extend(swap(
+            &mut self.right_hand,
+            Hand::Single(HandItem::Weapon(weapon)),
+        ));
+        replaced
+    }
+
+    /// Takes up a shield, returns whatever the hands let go of.
+    pub fn equip_shield(&mut self, shield: Shield) -> Vec<Item> {
+        let mut replaced = Vec::new();
+        if self.left_hand == Hand::Busy {
+            replaced.extend(swap(&mut self.right_hand, Hand::Empty));
+        }
+        replaced.extend(swap(
+            &mut self.left_hand,
+            Hand::Single(HandItem::Shield(shield)),
+        ));
+        replaced
+    }
+
+    pub fn equip_armor(&mut self, armor: Armor) -> Option<Armor> {
+        self.armor.equip(armor)
+    }
+
+    /// The item in a slot, if any.
+    pub fn get(&self, slot: Slot) -> Option<&dyn Equipable> {
+        match slot {
+            Slot::MainHand => self.weapon().map(|weapon| weapon as &dyn Equipable),
+            Slot::OffHand => self.shield().map(|shield| shield as &dyn Equipable),
+            Slot::Armor(armor_type) => self.armor.get(armor_type).map(|armor| armor as _),
+        }
+    }
+
+    pub fn get_mut(&mut self, slot: Slot) -> Option<&mut dyn Equipable> {
+        match slot {
+            Slot::MainHand => self.weapon_mut().map(|weapon| weapon as &mut dyn Equipable),
+            Slot::OffHand => self.shield_mut().map(|shield| shield as &mut dyn Equipable),
+            Slot::Armor(armor_type) => self.armor.get_mut(armor_type).map(|armor| armor as _),
         }
     }
 
-    pub fn equip_shield(&mut self, shield: Shield) {
-        match &self.left_hand {
-            Hand::Empty => self.left_hand = Hand::Single(HandItem::Shield(shield)),
-            Hand::Single(HandItem::Shield(_)) => {
-                self.left_hand = Hand::Single(HandItem::Shield(shield))
+    /// Wears down the item in a slot. An item that breaks is thrown
+    /// away, its name is returned.
+    pub fn wear(&mut self, slot: Slot, amount: u8) -> Option<String> {
+        let item = self.get_mut(slot)?;
+        if !item.durability_mut().wear(amount) {
+            return None;
+        }
+        let name = item.name().to_string();
+        self.take_off(slot);
+        Some(name)
+    }
+
+    fn take_off(&mut self, slot: Slot) {
+        match slot {
+            Slot::MainHand => {
+                for hand in [&mut self.right_hand, &mut self.left_hand] {
+                    if matches!(hand, Hand::Single(HandItem::Weapon(_)) | Hand::Busy) {
+                        *hand = Hand::Empty;
+                    }
+                }
             }
-            Hand::Single(HandItem::Weapon(_)) => {
-                self.left_hand = Hand::Single(HandItem::Shield(shield))
+            Slot::OffHand => {
+                for hand in [&mut self.left_hand, &mut self.right_hand] {
+                    if matches!(hand, Hand::Single(HandItem::Shield(_))) {
+                        *hand = Hand::Empty;
+                    }
+                }
             }
-            Hand::Busy => {
-                self.left_hand = Hand::Single(HandItem::Shield(shield));
-                self.right_hand = Hand::Empty;
+            Slot::Armor(armor_type) => {
+                self.armor.take_off(armor_type);
             }
         }
     }
 
-    pub fn equip_armor(&mut self, armor: Armor) {
-        match armor.armor_type {
-            ArmorType::Helmet => self.armor.helmet = Some(armor),
-            ArmorType::BreastPlate => self.armor.breastplate = Some(armor),
-            ArmorType::Legs => self.armor.boots = Some(armor),
-        }
+    pub fn weapon(&self) -> Option<&Weapon> {
+        [&self.right_hand, &self.left_hand]
+            .into_iter()
+            .find_map(|hand| match hand {
+                Hand::Single(HandItem::Weapon(weapon)) => Some(weapon),
+                _ => None,
+            })
     }
 
-    pub fn to_pretty_table(&self) {
-        let mut table = Table::new();
-        table.add_row(row!["Slot", "Item", "Details"]);
+    pub fn shield(&self) -> Option<&Shield> {
+        [&self.left_hand, &self.right_hand]
+            .into_iter()
+            .find_map(|hand| match hand {
+                Hand::Single(HandItem::Shield(shield)) => Some(shield),
+                _ => None,
+            })
+    }
+
+    fn weapon_mut(&mut self) -> Option<&mut Weapon> {
+        [&mut self.right_hand, &mut self.left_hand]
+            .into_iter()
+            .find_map(|hand| match hand {
+                Hand::Single(HandItem::Weapon(weapon)) => Some(weapon),
+                _ => None,
+            })
+    }
+
+    fn shield_mut(&mut self) -> Option<&mut Shield> {
+        [&mut self.left_hand, &mut self.right_hand]
+            .into_iter()
+            .find_map(|hand| match hand {
+                Hand::Single(HandItem::Shield(shield)) => Some(shield),
+                _ => None,
+            })
+    }
+
+    pub fn weight(&self) -> u32 {
+        let shield_weight = self
+            .shield()
+            .map_or(0, |shield| shield.weight.max(0) as u32);
+        shield_weight + self.armor.weight()
+    }
+
+    pub fn equipment_rows(&self) -> Table {
+        let mut table = Table::new(&["Slot", "Item", "Details"]);
 
         // Handle hands equipment
-        match &self.left_hand {
-            Hand::Busy => table.add_row(row!["Left Hand", "Busy", ""]),
-            Hand::Empty => table.add_row(row!["Left Hand", "Empty", ""]),
-            Hand::Single(item) => match item {
-                HandItem::Weapon(weapon) => table.add_row(row![
-                    "Left Hand",
+        for (slot, hand) in [
+            ("Left Hand", &self.left_hand),
+            ("Right Hand", &self.right_hand),
+        ] {
+            let (item, details) = match hand {
+                Hand::Busy => ("Busy", String::new()),
+                Hand::Empty => ("Empty", String::new()),
+                Hand::Single(HandItem::Weapon(weapon)) => (
                     "Weapon",
                     format!(
-                        "{}: {}-{} dmg, req str: {}",
-                        weapon.name, weapon.min_damage, weapon.max_damage, weapon.req_strength
-                    )
-                ]),
-                HandItem::Shield(shield) => table.add_row(row![
-                    "Left Hand",
+                        "{}: {}-{} dmg, req str: {}, {} durability",
+                        weapon.name,
+                        weapon.min_damage,
+                        weapon.max_damage,
+                        weapon.req_strength,
+                        weapon.durability
+                    ),
+                ),
+                Hand::Single(HandItem::Shield(shield)) => (
                     "Shield",
                     format!(
-                        "{}: {} def, req str: {}",
-                        shield.name, shield.block_damage, shield.req_strength
-                    )
-                ]),
-            },
-        };
-
-        match &self.right_hand {
-            Hand::Busy => table.add_row(row!["Right Hand", "Busy", ""]),
-            Hand::Empty => table.add_row(row!["Right Hand", "Empty", ""]),
-            Hand::Single(item) => match item {
-                HandItem::Weapon(weapon) => table.add_row(row![
-                    "Right Hand",
-                    "Weapon",
-                    format!(
-                        "{}: {}-{} dmg, req str: {}",
-                        weapon.name, weapon.min_damage, weapon.max_damage, weapon.req_strength
-                    )
-                ]),
-                HandItem::Shield(shield) => table.add_row(row![
-                    "Right Hand",
-                    "Shield",
-                    format!(
-                        "{}: {} def, req str: {}",
-                        shield.name, shield.block_damage, shield.req_strength
-                    )
-                ]),
-            },
-        };
-
-        // Handle armor equipment
-        if let Some(helmet) = &self.armor.helmet {
-            table.add_row(row![
-                "Helmet",
-                helmet.name.clone(),
-                format!("{} def, req str: {}", helmet.defense, helmet.req_strength)
-            ]);
+                        "{}: {} def, req str: {}, {} durability",
+                        shield.name, shield.block_damage, shield.req_strength, shield.durability
+                    ),
+                ),
+            };
+            table
+                .rows
+                .push(vec![slot.to_string(), item.to_string(), details]);
         }
 
-        if let Some(breastplate) = &self.armor.breastplate {
-            table.add_row(row![
-                "Breastplate",
-                breastplate.name.clone(),
-                format!(
-                    "{} def, req str: {}",
-                    breastplate.defense, breastplate.req_strength
-                )
-            ]);
+        // Handle armor equipment
+        for armor_type in ArmorType::ALL {
+            if let Some(armor) = self.armor.get(armor_type) {
+                table.rows.push(vec![
+                    armor_type.to_string(),
+                    armor.name.clone(),
+                    format!(
+                        "{} def, req str: {}, {} durability",
+                        armor.defense, armor.req_strength, armor.durability
+                    ),
+                ]);
+            }
         }
 
-        if let Some(boots) = &self.armor.boots {
-            table.add_row(row![
-                "Boots",
-                boots.name.clone(),
-                format!("{} def, req str: {}", boots.defense, boots.req_strength)
-            ]);
-        }
+        table
+    }
+}
 
-        // Print the table to stdout
-        table.printstd();
+/// Puts something else in a hand, returns the item it held.
+fn swap(hand: &mut Hand, with: Hand) -> Option<Item> {
+    match std::mem::replace(hand, with) {
+        Hand::Single(item) => Some(item.into()),
+        Hand::Empty | Hand::Busy => None,
     }
 }
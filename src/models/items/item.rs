@@ -1,7 +1,260 @@
+use super::armor::ArmorType;
+use super::durability::Durability;
+use super::hand_item::{HandItem, HandItemType};
+use super::loot::Rarity;
 use super::{armor::Armor, shield::Shield, weapon::Weapon};
+use serde::{Deserialize, Serialize};
+use std::fmt;
 
+/// What every piece of gear has in common, whatever it is.
+pub trait Equipable {
+    fn name(&self) -> &str;
+    fn price(&self) -> i32;
+    /// Weight slowing down the wearer's evasion.
+    fn weight(&self) -> u32;
+    fn req_strength(&self) -> u8;
+    fn slot(&self) -> Slot;
+    /// Short summary of what the item does in a fight.
+    fn stats(&self) -> String;
+    fn durability(&self) -> Durability;
+    fn durability_mut(&mut self) -> &mut Durability;
+    fn rarity(&self) -> Rarity;
+}
+
+/// Where an item goes when equipped.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Slot {
+    /// Weapons go in the right hand, two-handed ones take both.
+    MainHand,
+    OffHand,
+    Armor(ArmorType),
+}
+
+impl fmt::Display for Slot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Slot::MainHand => write!(f, "Main Hand"),
+            Slot::OffHand => write!(f, "Off Hand"),
+            Slot::Armor(armor_type) => write!(f, "{}", armor_type),
+        }
+    }
+}
+
+impl Slot {
+    pub const ALL: [Slot; 8] = [
+        Slot::MainHand,
+        Slot::OffHand,
+        Slot::Armor(ArmorType::Helmet),
+        Slot::Armor(ArmorType::Shoulder),
+        Slot::Armor(ArmorType::BreastPlate),
+        Slot::Armor(ArmorType::Gloves),
+        Slot::Armor(ArmorType::Legs),
+        Slot::Armor(ArmorType::Boots),
+    ];
+
+    /// Name of the menus listing the items for this slot.
+    pub fn category(&self) -> &'static str {
+        match self {
+            Slot::MainHand => "Weapons",
+            Slot::OffHand => "Shields",
+            Slot::Armor(ArmorType::Helmet) => "Helmets",
+            Slot::Armor(ArmorType::Shoulder) => "Shoulder Guards",
+            Slot::Armor(ArmorType::BreastPlate) => "Breastplates",
+            Slot::Armor(ArmorType::Gloves) => "Gloves",
+            Slot::Armor(ArmorType::Legs) => "Leg Armor",
+            Slot::Armor(ArmorType::Boots) => "Boots",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 pub enum Item {
     Weapon(Weapon),
     Shield(Shield),
     Armor(Armor),
 }
+
+impl Item {
+    fn equipable(&self) -> &dyn Equipable {
+        match self {
+            Item::Weapon(weapon) => weapon,
+            Item::Shield(shield) => shield,
+            Item::Armor(armor) => armor,
+        }
+    }
+
+    fn equipable_mut(&mut self) -> &mut dyn Equipable {
+        match self {
+            Item::Weapon(weapon) => weapon,
+            Item::Shield(shield) => shield,
+            Item::Armor(armor) => armor,
+        }
+    }
+}
+
+impl From<HandItem> for Item {
+    fn from(item: HandItem) -> Self {
+        match item {
+            HandItem::Weapon(weapon) => Item::Weapon(weapon),
+            HandItem::Shield(shield) => Item::Shield(shield),
+        }
+    }
+}
+
+impl Equipable for Item {
+    fn name(&self) -> &str {
+        self.equipable().name()
+    }
+
+    fn price(&self) -> i32 {
+        self.equipable().price()
+    }
+
+    fn weight(&self) -> u32 {
+        self.equipable().weight()
+    }
+
+    fn req_strength(&self) -> u8 {
+        self.equipable().req_strength()
+    }
+
+    fn slot(&self) -> Slot {
+        self.equipable().slot()
+    }
+
+    fn stats(&self) -> String {
+        self.equipable().stats()
+    }
+
+    fn durability(&self) -> Durability {
+        self.equipable().durability()
+    }
+
+    fn durability_mut(&mut self) -> &mut Durability {
+        self.equipable_mut().durability_mut()
+    }
+
+    fn rarity(&self) -> Rarity {
+        self.equipable().rarity()
+    }
+}
+
+impl Equipable for Weapon {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn price(&self) -> i32 {
+        self.price
+    }
+
+    /// Weapons are swung, not worn, and don't slow down evasion.
+    fn weight(&self) -> u32 {
+        0
+    }
+
+    fn req_strength(&self) -> u8 {
+        self.req_strength
+    }
+
+    fn slot(&self) -> Slot {
+        Slot::MainHand
+    }
+
+    fn stats(&self) -> String {
+        match self.item_type {
+            HandItemType::Single => format!("{}-{} dmg", self.min_damage, self.max_damage),
+            HandItemType::Double => {
+                format!("{}-{} dmg, two-handed", self.min_damage, self.max_damage)
+            }
+        }
+    }
+
+    fn durability(&self) -> Durability {
+        self.durability
+    }
+
+    fn durability_mut(&mut self) -> &mut Durability {
+        &mut self.durability
+    }
+
+    fn rarity(&self) -> Rarity {
+        self.rarity
+    }
+}
+
+impl Equipable for Shield {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn price(&self) -> i32 {
+        self.price
+    }
+
+    fn weight(&self) -> u32 {
+        self.weight.max(0) as u32
+    }
+
+    fn req_strength(&self) -> u8 {
+        self.req_strength
+    }
+
+    fn slot(&self) -> Slot {
+        Slot::OffHand
+    }
+
+    fn stats(&self) -> String {
+        format!("{} block", self.block_damage)
+    }
+
+    fn durability(&self) -> Durability {
+        self.durability
+    }
+
+    fn durability_mut(&mut self) -> &mut Durability {
+        &mut self.durability
+    }
+
+    fn rarity(&self) -> Rarity {
+        self.rarity
+    }
+}
+
+impl Equipable for Armor {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn price(&self) -> i32 {
+        self.price as i32
+    }
+
+    fn weight(&self) -> u32 {
+        self.weight
+    }
+
+    fn req_strength(&self) -> u8 {
+        self.req_strength
+    }
+
+    fn slot(&self) -> Slot {
+        Slot::Armor(self.armor_type)
+    }
+
+    fn stats(&self) -> String {
+        format!("{} def", self.defense)
+    }
+
+    fn durability(&self) -> Durability {
+        self.durability
+    }
+
+    fn durability_mut(&mut self) -> &mut Durability {
+        &mut self.durability
+    }
+
+    fn rarity(&self) -> Rarity {
+        self.rarity
+    }
+}
@@ -0,0 +1,431 @@
+use super::catalog;
+use super::item::Item;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// Luck adds up to this much to the rarity roll, out of 100
+const MAX_LUCK_BONUS: u32 = 50;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Rarity {
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+    Legendary,
+}
+
+impl fmt::Display for Rarity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rarity::Common => write!(f, "Common"),
+            Rarity::Uncommon => write!(f, "Uncommon"),
+            Rarity::Rare => write!(f, "Rare"),
+            Rarity::Epic => write!(f, "Epic"),
+            Rarity::Legendary => write!(f, "Legendary"),
+        }
+    }
+}
+
+impl Rarity {
+    /// Out of 100 rolls 60 are common, 25 uncommon, 10 rare, 4 epic and
+    /// 1 legendary. Luck, from 0 to 100, pushes the roll up.
+    pub fn roll<R: Rng>(rng: &mut R, luck: u8) -> Self {
+        let roll = rng.gen_range(0, 100) + luck.min(100) as u32 * MAX_LUCK_BONUS / 100;
+        match roll {
+            0..=59 => Rarity::Common,
+            60..=84 => Rarity::Uncommon,
+            85..=94 => Rarity::Rare,
+            95..=98 => Rarity::Epic,
+            _ => Rarity::Legendary,
+        }
+    }
+
+    /// Percentage added to the damage, block or defense of the base item.
+    fn bonus(&self) -> i32 {
+        match self {
+            Rarity::Common => 0,
+            Rarity::Uncommon => 10,
+            Rarity::Rare => 20,
+            Rarity::Epic => 35,
+            Rarity::Legendary => 50,
+        }
+    }
+
+    /// Price of the item relative to the base one, in percent.
+    fn price(&self) -> i32 {
+        match self {
+            Rarity::Common => 100,
+            Rarity::Uncommon => 150,
+            Rarity::Rare => 250,
+            Rarity::Epic => 400,
+            Rarity::Legendary => 700,
+        }
+    }
+
+    /// Uncommon and rare items get a prefix or a suffix, the best both.
+    fn affixes(&self) -> usize {
+        match self {
+            Rarity::Common => 0,
+            Rarity::Uncommon | Rarity::Rare => 1,
+            Rarity::Epic | Rarity::Legendary => 2,
+        }
+    }
+}
+
+/// A quality an item was made with, which shows in its name.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Affix {
+    Balanced,
+    Sharp,
+    Heavy,
+    Light,
+    Reinforced,
+    OfTheLegion,
+    OfMars,
+    OfVulcan,
+}
+
+impl fmt::Display for Affix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Affix::Balanced => write!(f, "Balanced"),
+            Affix::Sharp => write!(f, "Sharp"),
+            Affix::Heavy => write!(f, "Heavy"),
+            Affix::Light => write!(f, "Light"),
+            Affix::Reinforced => write!(f, "Reinforced"),
+            Affix::OfTheLegion => write!(f, "of the Legion"),
+            Affix::OfMars => write!(f, "of Mars"),
+            Affix::OfVulcan => write!(f, "of Vulcan"),
+        }
+    }
+}
+
+/// Changes an affix makes to an item. Damage only matters for weapons,
+/// protection is the block of a shield or the defense of armor.
+#[derive(Debug, Default, Clone, Copy)]
+struct Modifiers {
+    damage: i32,
+    protection: i32,
+    weight: i32,
+    req_strength: i32,
+}
+
+impl Affix {
+    pub const ALL: [Affix; 8] = [
+        Affix::Balanced,
+        Affix::Sharp,
+        Affix::Heavy,
+        Affix::Light,
+        Affix::Reinforced,
+        Affix::OfTheLegion,
+        Affix::OfMars,
+        Affix::OfVulcan,
+    ];
+
+    /// Prefixes go before the name of the item, suffixes after it.
+    pub fn is_prefix(&self) -> bool {
+        !matches!(self, Affix::OfTheLegion | Affix::OfMars | Affix::OfVulcan)
+    }
+
+    fn fits(&self, item: &Item) -> bool {
+        let is_weapon = matches!(item, Item::Weapon(_));
+        match self {
+            Affix::Balanced | Affix::Sharp | Affix::OfMars => is_weapon,
+            // Weapons carry no weight for these to change
+            Affix::Heavy | Affix::Light | Affix::Reinforced | Affix::OfVulcan => !is_weapon,
+            Affix::OfTheLegion => true,
+        }
+    }
+
+    fn modifiers(&self) -> Modifiers {
+        match self {
+            Affix::Balanced => Modifiers {
+                damage: 1,
+                req_strength: -3,
+                ..Modifiers::default()
+            },
+            Affix::Sharp => Modifiers {
+                damage: 3,
+                ..Modifiers::default()
+            },
+            Affix::Heavy => Modifiers {
+                protection: 2,
+                weight: 8,
+                req_strength: 5,
+                ..Modifiers::default()
+            },
+            Affix::Light => Modifiers {
+                protection: -1,
+                weight: -5,
+                req_strength: -3,
+                ..Modifiers::default()
+            },
+            Affix::Reinforced => Modifiers {
+                protection: 3,
+                weight: 3,
+                ..Modifiers::default()
+            },
+            Affix::OfTheLegion => Modifiers {
+                damage: 1,
+                protection: 2,
+                req_strength: -2,
+                ..Modifiers::default()
+            },
+            Affix::OfMars => Modifiers {
+                damage: 4,
+                req_strength: 3,
+                ..Modifiers::default()
+            },
+            Affix::OfVulcan => Modifiers {
+                protection: 3,
+                weight: -2,
+                ..Modifiers::default()
+            },
+        }
+    }
+}
+
+/// Rolls a random item from the catalog. `luck`, from 0 to 100,
+/// makes the better rarities more likely.
+pub fn roll<R: Rng>(rng: &mut R, luck: u8) -> Item {
+    let bases: Vec<Item> = catalog::weapons()
+        .into_iter()
+        .map(Item::Weapon)
+        .chain(catalog::shields().into_iter().map(Item::Shield))
+        .chain(catalog::armors().into_iter().map(Item::Armor))
+        .collect();
+    let base = bases
+        .choose(rng)
+        .cloned()
+        .expect("the catalog is never empty");
+    refine(rng, base, luck)
+}
+
+/// Rolls the rarity and affixes of a given base item.
+pub fn refine<R: Rng>(rng: &mut R, base: Item, luck: u8) -> Item {
+    let rarity = Rarity::roll(rng, luck);
+    let affixes = roll_affixes(rng, rarity, &base);
+    enchant(base, rarity, affixes)
+}
+
+/// At most one prefix and one suffix.
+fn roll_affixes<R: Rng>(rng: &mut R, rarity: Rarity, item: &Item) -> Vec<Affix> {
+    let pick = |rng: &mut R, prefix: bool| {
+        Affix::ALL
+            .into_iter()
+            .filter(|affix| affix.is_prefix() == prefix && affix.fits(item))
+            .collect::<Vec<_>>()
+            .choose(rng)
+            .copied()
+    };
+    match rarity.affixes() {
+        0 => Vec::new(),
+        1 => {
+            let prefix = rng.gen_bool(0.5);
+            pick(rng, prefix).into_iter().collect()
+        }
+        _ => pick(rng, true)
+            .into_iter()
+            .chain(pick(rng, false))
+            .collect(),
+    }
+}
+
+/// Applies the rarity and affixes to a base item, renaming it after them.
+fn enchant(item: Item, rarity: Rarity, affixes: Vec<Affix>) -> Item {
+    let mut total = Modifiers::default();
+    for modifiers in affixes.iter().map(Affix::modifiers) {
+        total.damage += modifiers.damage;
+        total.protection += modifiers.protection;
+        total.weight += modifiers.weight;
+        total.req_strength += modifiers.req_strength;
+    }
+    let improve = |stat: u8, change: i32| -> u8 {
+        (stat as i32 * (100 + rarity.bonus()) / 100 + change).clamp(1, u8::MAX as i32) as u8
+    };
+    let req_strength = |req: u8| (req as i32 + total.req_strength).clamp(0, u8::MAX as i32) as u8;
+    let price = |price: i32| price * rarity.price() / 100;
+
+    let prefix: Vec<String> = affixes
+        .iter()
+        .filter(|affix| affix.is_prefix())
+        .map(Affix::to_string)
+        .collect();
+    let suffix: Vec<String> = affixes
+        .iter()
+        .filter(|affix| !affix.is_prefix())
+        .map(Affix::to_string)
+        .collect();
+    let name = |base: &str| {
+        prefix
+            .iter()
+            .map(String::as_str)
+            .chain([base])
+            .chain(suffix.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    match item {
+        Item::Weapon(mut weapon) => {
+            weapon.name = name(&weapon.name);
+            weapon.min_damage = improve(weapon.min_damage, total.damage);
+            weapon.max_damage = improve(weapon.max_damage, total.damage).max(weapon.min_damage);
+            weapon.req_strength = req_strength(weapon.req_strength);
+            weapon.price = price(weapon.price);
+            weapon.rarity = rarity;
+            weapon.affixes = affixes;
+            Item::Weapon(weapon)
+        }
+        Item::Shield(mut shield) => {
+            shield.name = name(&shield.name);
+            shield.block_damage = improve(shield.block_damage, total.protection);
+            shield.weight = (shield.weight + total.weight).max(0);
+            shield.req_strength = req_strength(shield.req_strength);
+            shield.price = price(shield.price);
+            shield.rarity = rarity;
+            shield.affixes = affixes;
+            Item::Shield(shield)
+        }
+        Item::Armor(mut armor) => {
+            armor.name = name(&armor.name);
+            armor.defense = improve(armor.defense, total.protection);
+            armor.weight = (armor.weight as i32 + total.weight).max(0) as u32;
+            armor.req_strength = req_strength(armor.req_strength);
+            armor.price = price(armor.price as i32) as u32;
+            armor.rarity = rarity;
+            armor.affixes = affixes;
+            Item::Armor(armor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::items::armor::{Armor, ArmorType};
+    use crate::models::items::hand_item::HandItemType;
+    use crate::models::items::shield::Shield;
+    use crate::models::items::weapon::Weapon;
+    use crate::models::rng::GameRng;
+
+    const RARITIES: [Rarity; 5] = [
+        Rarity::Common,
+        Rarity::Uncommon,
+        Rarity::Rare,
+        Rarity::Epic,
+        Rarity::Legendary,
+    ];
+
+    fn weapon() -> Item {
+        Item::Weapon(Weapon::new(
+            "Gladius".to_string(),
+            HandItemType::Single,
+            1,
+            2,
+            3,
+            100,
+        ))
+    }
+
+    fn flimsy_shield() -> Item {
+        Item::Shield(Shield::new(
+            "Parma".to_string(),
+            HandItemType::Single,
+            1,
+            3,
+            100,
+            2,
+        ))
+    }
+
+    fn flimsy_armor() -> Item {
+        Item::Armor(Armor::new(
+            "Manica".to_string(),
+            ArmorType::Gloves,
+            1,
+            3,
+            100,
+            3,
+        ))
+    }
+
+    #[test]
+    fn weapons_never_get_armor_affixes() {
+        let mut rng = GameRng::new(1);
+        for _ in 0..500 {
+            let affixes = roll_affixes(&mut rng, Rarity::Legendary, &weapon());
+            assert!(
+                !affixes.iter().any(|affix| matches!(
+                    affix,
+                    Affix::Heavy | Affix::Light | Affix::Reinforced | Affix::OfVulcan
+                )),
+                "{:?}",
+                affixes
+            );
+        }
+    }
+
+    #[test]
+    fn every_rarity_gets_its_number_of_affixes() {
+        let mut rng = GameRng::new(1);
+        for base in [weapon(), flimsy_shield(), flimsy_armor()] {
+            for rarity in RARITIES {
+                for _ in 0..50 {
+                    let affixes = roll_affixes(&mut rng, rarity, &base);
+                    assert_eq!(affixes.len(), rarity.affixes(), "{}", rarity);
+                    // Never two prefixes or two suffixes
+                    assert!(affixes.iter().filter(|affix| affix.is_prefix()).count() <= 1);
+                    assert!(affixes.iter().filter(|affix| !affix.is_prefix()).count() <= 1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn stats_never_drop_below_one_and_weight_below_zero() {
+        let light = vec![Affix::Light, Affix::OfVulcan];
+        let Item::Shield(shield) = enchant(flimsy_shield(), Rarity::Epic, light.clone()) else {
+            panic!("enchanting keeps the kind of item");
+        };
+        assert_eq!(shield.name, "Light Parma of Vulcan");
+        assert!(shield.block_damage >= 1);
+        assert_eq!(shield.weight, 0);
+        assert_eq!(shield.req_strength, 0);
+
+        let Item::Armor(armor) = enchant(flimsy_armor(), Rarity::Epic, light) else {
+            panic!("enchanting keeps the kind of item");
+        };
+        assert!(armor.defense >= 1);
+        assert_eq!(armor.weight, 0);
+        assert_eq!(armor.req_strength, 0);
+    }
+
+    #[test]
+    fn rarity_raises_stats_and_price() {
+        let Item::Weapon(common) = enchant(weapon(), Rarity::Common, Vec::new()) else {
+            panic!("enchanting keeps the kind of item");
+        };
+        let Item::Weapon(legendary) = enchant(weapon(), Rarity::Legendary, vec![Affix::Sharp])
+        else {
+            panic!("enchanting keeps the kind of item");
+        };
+        assert_eq!(common.name, "Gladius");
+        assert_eq!(legendary.name, "Sharp Gladius");
+        assert!(legendary.min_damage > common.min_damage);
+        assert!(legendary.max_damage >= legendary.min_damage);
+        assert_eq!(legendary.price, common.price * 7);
+    }
+
+    #[test]
+    fn the_same_seed_rolls_the_same_item() {
+        for seed in 0..20 {
+            let first = roll(&mut GameRng::new(seed), 50);
+            let second = roll(&mut GameRng::new(seed), 50);
+            assert_eq!(first, second);
+        }
+    }
+}
@@ -1,5 +1,8 @@
 pub mod armor;
+pub mod catalog;
+pub mod durability;
 pub mod hand_item;
 pub mod item;
+pub mod loot;
 pub mod shield;
 pub mod weapon;
@@ -1,5 +1,6 @@
+use super::durability::Durability;
 use super::hand_item::HandItemType;
-use prettytable::{cell, row, Row};
+use super::loot::{Affix, Rarity};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
@@ -10,6 +11,9 @@ pub struct Shield {
     pub req_strength: u8,
     pub price: i32,
     pub weight: i32,
+    pub durability: Durability,
+    pub rarity: Rarity,
+    pub affixes: Vec<Affix>,
 }
 
 impl Shield {
@@ -28,17 +32,9 @@ impl Shield {
             req_strength,
             price,
             weight,
+            durability: Durability::default(),
+            rarity: Rarity::Common,
+            affixes: Vec::new(),
         }
     }
-
-    pub fn to_row(&self) -> Row {
-        row![
-            &self.name,
-            &self.item_type,
-            &self.block_damage,
-            &self.weight,
-            &self.req_strength,
-            &self.price
-        ]
-    }
 }
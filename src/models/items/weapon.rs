@@ -1,5 +1,6 @@
+use super::durability::Durability;
 use super::hand_item::HandItemType;
-use prettytable::{cell, row};
+use super::loot::{Affix, Rarity};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
@@ -10,6 +11,9 @@ pub struct Weapon {
     pub max_damage: u8,
     pub req_strength: u8,
     pub price: i32,
+    pub durability: Durability,
+    pub rarity: Rarity,
+    pub affixes: Vec<Affix>,
 }
 
 impl Weapon {
@@ -28,15 +32,9 @@ impl Weapon {
             max_damage,
             req_strength,
             price,
+            durability: Durability::default(),
+            rarity: Rarity::Common,
+            affixes: Vec::new(),
         }
     }
-
-    pub fn to_row(&self) -> prettytable::Row {
-        row![
-            &self.name,
-            &self.item_type,
-            format!("{}-{}", self.min_damage, self.max_damage),
-            self.req_strength.to_string()
-        ]
-    }
 }
@@ -0,0 +1,309 @@
+use super::combat::{max_energy, max_health};
+use super::items::durability::MAX_DURABILITY;
+use super::items::loot::Rarity;
+use super::player::Fame;
+use super::rng::GameRng;
+use super::store::Store;
+use serde_json::{json, Map, Value};
+
+/// Version written into every new save file.
+pub const SAVE_VERSION: u32 = 1;
+
+type Migration = fn(Value) -> Value;
+
+// MIGRATIONS[n] upgrades a version n document to version n + 1
+const MIGRATIONS: &[Migration] = &[migrate_v0_to_v1];
+
+/// Saves written before the format was versioned are a bare `Game`.
+pub fn detect_version(document: &Value) -> u32 {
+    document
+        .get("version")
+        .and_then(Value::as_u64)
+        .map_or(0, |version| version as u32)
+}
+
+/// Upgrades a save document to the current version.
+/// Returns the version found when the document is newer than this build.
+pub fn migrate(mut document: Value) -> Result<Value, u32> {
+    let version = detect_version(&document);
+    if version > SAVE_VERSION {
+        return Err(version);
+    }
+    for migration in &MIGRATIONS[version as usize..] {
+        document = migration(document);
+    }
+    Ok(document)
+}
+
+/// v0 was a bare `Game` with a UI `state`, a week counter, one list per kind
+/// of item and three armor slots. v1 wraps the game with metadata and adds
+/// everything the engine has learned since: character origins, injuries,
+/// relationships, the calendar, the rebellion, durability and rarity.
+fn migrate_v0_to_v1(mut game: Value) -> Value {
+    let metadata = {
+        let player = game.get("player").cloned().unwrap_or(Value::Null);
+        json!({
+            "name": "Old save",
+            "player_name": player.get("name").cloned().unwrap_or(json!("Unknown")),
+            "week": game.get("current_week").cloned().unwrap_or(json!(0)),
+            "victories": player.get("victories").cloned().unwrap_or(json!(0)),
+            "money": player.get("money").cloned().unwrap_or(json!(0)),
+            "saved_at": 0,
+        })
+    };
+
+    if let Some(player) = game.get_mut("player").and_then(Value::as_object_mut) {
+        migrate_player(player);
+    }
+    if let Some(game) = game.as_object_mut() {
+        let phase = match game.remove("state").as_ref().and_then(Value::as_str) {
+            Some("GameOver") => json!({ "Over": "Death" }),
+            _ => json!("Ludus"),
+        };
+        game.insert("phase".to_string(), phase);
+
+        let week = game
+            .remove("current_week")
+            .and_then(|week| week.as_u64())
+            .unwrap_or(0);
+        game.remove("is_fight_week");
+        game.insert("calendar".to_string(), json!({ "week": week }));
+        game.insert(
+            "rebellion".to_string(),
+            json!({ "stage": "Dormant", "recruits": 0, "preparation": 0, "suspicion": 0 }),
+        );
+
+        // Runs started from a seed already carry their generator
+        let mut rng: GameRng = game
+            .get("rng")
+            .and_then(|rng| serde_json::from_value(rng.clone()).ok())
+            .unwrap_or_else(GameRng::from_entropy);
+        // The old stock predates most of the catalog, the store opens anew
+        game.insert("store".to_string(), json!(Store::new(&mut rng)));
+        game.insert("rng".to_string(), json!(rng));
+    }
+
+    json!({ "version": 1, "metadata": metadata, "game": game })
+}
+
+/// Every old gladiator was a prisoner of war with the base endurance.
+fn migrate_player(player: &mut Map<String, Value>) {
+    player.insert("origin".to_string(), json!("PrisonerOfWar"));
+    player.insert("endurance".to_string(), json!(5));
+    player.insert(
+        "relationships".to_string(),
+        json!({ "lanista": 0, "gladiators": 0 }),
+    );
+    player.remove("injured");
+    player.insert("injuries".to_string(), json!([]));
+
+    // Fame levels follow fame points, which could go negative before
+    let fame = player
+        .get("fame")
+        .and_then(Value::as_i64)
+        .unwrap_or(0)
+        .max(0);
+    player.insert("fame".to_string(), json!(fame));
+    player.insert(
+        "fame_level".to_string(),
+        json!(Fame::from_points(fame as i32)),
+    );
+
+    // Max health and energy are derived from endurance
+    player.remove("max_health");
+    let endurance = player.get("endurance").and_then(Value::as_u64).unwrap_or(5) as u8;
+    for (field, max) in [
+        ("health", max_health(endurance) as i64),
+        ("energy", max_energy(endurance) as i64),
+    ] {
+        let value = player.get(field).and_then(Value::as_i64).unwrap_or(max);
+        player.insert(field.to_string(), json!(value.min(max)));
+    }
+
+    // Leg armor used to be kept as `boots`
+    if let Some(armor) = player
+        .get_mut("equipment")
+        .and_then(|equipment| equipment.get_mut("armor"))
+        .and_then(Value::as_object_mut)
+    {
+        let legs = armor.remove("boots").unwrap_or(Value::Null);
+        armor.insert("legs".to_string(), legs);
+        for slot in ["shoulders", "gloves", "boots"] {
+            armor.entry(slot).or_insert(Value::Null);
+        }
+    }
+
+    if let Some(inventory) = player.get_mut("inventory") {
+        *inventory = merge_inventory(inventory);
+    }
+    for (field, default) in [
+        ("durability", json!(MAX_DURABILITY)),
+        ("rarity", json!(Rarity::Common)),
+        ("affixes", json!([])),
+    ] {
+        player
+            .values_mut()
+            .for_each(|value| add_to_items(value, field, &default));
+    }
+    remove_equipped_copies(player);
+}
+
+/// Equipping used to leave a copy of the item in the inventory,
+/// it is now moved out of it.
+fn remove_equipped_copies(player: &mut Map<String, Value>) {
+    let mut equipped: Vec<Value> = Vec::new();
+    if let Some(equipment) = player.get("equipment") {
+        for hand in ["left_hand", "right_hand"] {
+            if let Some(item) = equipment.get(hand).and_then(|hand| hand.get("Single")) {
+                equipped.push(item.clone());
+            }
+        }
+        if let Some(armor) = equipment.get("armor").and_then(Value::as_object) {
+            equipped.extend(
+                armor
+                    .values()
+                    .filter(|piece| !piece.is_null())
+                    .map(|piece| json!({ "Armor": piece })),
+            );
+        }
+    }
+    if let Some(items) = player
+        .get_mut("inventory")
+        .and_then(|inventory| inventory.get_mut("items"))
+        .and_then(Value::as_object_mut)
+    {
+        for item in equipped {
+            let copy = items
+                .iter()
+                .find(|(_, stored)| **stored == item)
+                .map(|(id, _)| id.clone());
+            if let Some(id) = copy {
+                items.remove(&id);
+            }
+        }
+    }
+}
+
+/// Turns the separate weapons, shields and helmets lists into a single one keyed by id.
+fn merge_inventory(inventory: &Value) -> Value {
+    let list = |field: &str, kind: &str| -> Vec<Value> {
+        inventory
+            .get(field)
+            .and_then(Value::as_array)
+            .map(|items| items.iter().map(|item| json!({ kind: item })).collect())
+            .unwrap_or_default()
+    };
+    let items: Map<String, Value> = list("weapons", "Weapon")
+        .into_iter()
+        .chain(list("shields", "Shield"))
+        .chain(list("helmets", "Armor"))
+        .enumerate()
+        .map(|(id, item)| (id.to_string(), item))
+        .collect();
+    let next_id = items.len();
+    json!({ "items": items, "next_id": next_id })
+}
+
+/// Sets `field` on every weapon, shield and armor piece found in `value`
+/// that doesn't have it yet.
+fn add_to_items(value: &mut Value, field: &str, default: &Value) {
+    match value {
+        Value::Object(object) => {
+            let is_item = ["min_damage", "block_damage", "armor_type"]
+                .iter()
+                .any(|key| object.contains_key(*key));
+            if is_item && !object.contains_key(field) {
+                object.insert(field.to_string(), default.clone());
+            }
+            object
+                .values_mut()
+                .for_each(|value| add_to_items(value, field, default));
+        }
+        Value::Array(values) => values
+            .iter_mut()
+            .for_each(|value| add_to_items(value, field, default)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::game::{Game, Phase};
+    use crate::models::items::item::{Equipable, Slot};
+    use crate::models::save::load_game;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    // Saves of the first release and of this build, named `v<version>_<phase>.json`
+    fn fixtures() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/saves")
+    }
+
+    fn load(name: &str) -> Game {
+        load_game(&fixtures().join(name)).unwrap_or_else(|error| panic!("{}: {}", name, error))
+    }
+
+    /// Names of the catalog items on sale. Rolled loot may come up twice,
+    /// the catalog stock never should.
+    fn stock(game: &Game) -> Vec<&str> {
+        Slot::ALL
+            .iter()
+            .flat_map(|slot| game.store().items.items(*slot))
+            .filter(|(_, item)| item.rarity() == Rarity::Common)
+            .map(|(_, item)| item.name())
+            .collect()
+    }
+
+    #[test]
+    fn every_version_has_fixtures() {
+        // Saves of the current version catch format changes made without a migration
+        for version in 0..=SAVE_VERSION {
+            assert!(
+                fixtures().join(format!("v{}_ludus.json", version)).exists(),
+                "no fixture for v{}",
+                version
+            );
+        }
+    }
+
+    #[test]
+    fn old_saves_load_in_the_phase_they_were_saved_in() {
+        for entry in fs::read_dir(fixtures()).unwrap() {
+            let name = entry.unwrap().file_name().into_string().unwrap();
+            let game = load(&name);
+            let phase_kept = match name.split(['_', '.']).nth(1) {
+                Some("fighting") => matches!(game.phase(), Phase::Fighting(_)),
+                Some("verdict") => matches!(game.phase(), Phase::Verdict { .. }),
+                Some("spoils") => matches!(game.phase(), Phase::Spoils(_)),
+                _ => matches!(game.phase(), Phase::Ludus),
+            };
+            assert!(phase_kept, "{}: loaded as {:?}", name, game.phase());
+        }
+    }
+
+    #[test]
+    fn seeded_bare_saves_keep_their_generator() {
+        let contents = fs::read_to_string(fixtures().join("v0_ludus.json")).unwrap();
+        let mut document: Value = serde_json::from_str(&contents).unwrap();
+        let rng = json!({ "seed": 1234, "state": 99 });
+        document["rng"] = rng.clone();
+        let migrated = migrate(document.clone()).unwrap();
+        assert_eq!(migrated.pointer("/game/rng/seed"), rng.get("seed"));
+        // The store is rolled with it, the same save always migrates the same way
+        assert_eq!(migrate(document).unwrap(), migrated);
+    }
+
+    #[test]
+    fn migrated_stores_have_no_duplicate_items() {
+        for entry in fs::read_dir(fixtures()).unwrap() {
+            let name = entry.unwrap().file_name().into_string().unwrap();
+            let game = load(&name);
+            let mut stock = stock(&game);
+            let count = stock.len();
+            stock.sort();
+            stock.dedup();
+            assert_eq!(stock.len(), count, "{}: duplicate items in store", name);
+        }
+    }
+}
@@ -1,7 +1,21 @@
+pub mod ai;
+pub mod calendar;
+pub mod character;
+pub mod combat;
+pub mod command;
+pub mod crowd;
 pub mod enemy;
+pub mod event;
 pub mod game;
+pub mod injury;
 pub mod inventory;
 pub mod items;
+pub mod migration;
 pub mod player;
+pub mod rebellion;
+pub mod rng;
+pub mod save;
+pub mod spoils;
 pub mod store;
+pub mod training;
 pub mod utils;
@@ -1,10 +1,13 @@
+use super::character::{Attribute, Origin};
+use super::combat::{max_energy, max_health, tiredness_from, CombatStats, FightAction};
+use super::injury::{Injury, Penalties};
 use super::inventory::Inventory;
-use super::items::hand_item::Equipment;
-use super::utils::print_line;
-use prettytable::{cell, row, Cell, Row, Table};
+use super::items::hand_item::{Equipment, HandItemType};
+use super::utils::{health_bar, Table};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub enum Fame {
     Novice,
     Apprentice,
@@ -14,91 +17,303 @@ pub enum Fame {
     Legend,
 }
 
+impl fmt::Display for Fame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Fame::Novice => write!(f, "Novice"),
+            Fame::Apprentice => write!(f, "Apprentice"),
+            Fame::Veteran => write!(f, "Veteran"),
+            Fame::Champion => write!(f, "Champion"),
+            Fame::Hero => write!(f, "Hero"),
+            Fame::Legend => write!(f, "Legend"),
+        }
+    }
+}
+
+impl Fame {
+    const LEVELS: [Fame; 6] = [
+        Fame::Novice,
+        Fame::Apprentice,
+        Fame::Veteran,
+        Fame::Champion,
+        Fame::Hero,
+        Fame::Legend,
+    ];
+
+    /// Fame points needed to reach the level.
+    pub fn threshold(&self) -> i32 {
+        match self {
+            Fame::Novice => 0,
+            Fame::Apprentice => 25,
+            Fame::Veteran => 75,
+            Fame::Champion => 150,
+            Fame::Hero => 300,
+            Fame::Legend => 500,
+        }
+    }
+
+    pub fn from_points(fame: i32) -> Fame {
+        Fame::LEVELS
+            .into_iter()
+            .rev()
+            .find(|level| fame >= level.threshold())
+            .unwrap_or(Fame::Novice)
+    }
+
+    /// Chance that the crowd spares the gladiator when they beg for mercy.
+    pub fn mercy_chance(&self) -> f64 {
+        match self {
+            Fame::Novice => 0.4,
+            Fame::Apprentice => 0.5,
+            Fame::Veteran => 0.6,
+            Fame::Champion => 0.7,
+            Fame::Hero => 0.8,
+            Fame::Legend => 0.9,
+        }
+    }
+
+    /// Famous gladiators draw bigger crowds and earn bigger purses.
+    pub fn purse(&self, purse: i32) -> i32 {
+        let bonus = match self {
+            Fame::Novice => 0,
+            Fame::Apprentice => 10,
+            Fame::Veteran => 25,
+            Fame::Champion => 50,
+            Fame::Hero => 75,
+            Fame::Legend => 100,
+        };
+        purse * (100 + bonus) / 100
+    }
+
+    /// Merchants lower their prices to be seen arming a famous gladiator.
+    pub fn price(&self, price: i32) -> i32 {
+        let discount = match self {
+            Fame::Novice => 0,
+            Fame::Apprentice => 5,
+            Fame::Veteran => 10,
+            Fame::Champion => 15,
+            Fame::Hero => 20,
+            Fame::Legend => 25,
+        };
+        price * (100 - discount) / 100
+    }
+}
+
+/// What the lanista and the other gladiators of the ludus think of the player.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Relationships {
+    pub lanista: i32,
+    pub gladiators: i32,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Player {
     pub name: String,
+    pub origin: Origin,
     pub fame: i32,
     pub fame_level: Fame,
-    pub energy: u8, // max 255
+    pub energy: u8, // max derived from endurance
     pub health: i32,
-    pub max_health: i32,
     pub strength: u8,
     pub defense: u8,
     pub agility: u8,
+    pub endurance: u8,
     pub money: i32,
     pub inventory: Inventory,
     pub victories: i32,
-    pub injured: bool,
+    pub injuries: Vec<Injury>,
     pub equipment: Equipment,
+    pub relationships: Relationships,
 }
 
 impl Player {
-    pub fn new(name: String) -> Player {
+    pub fn new(name: String, origin: Origin) -> Player {
         let inventory = Inventory::new();
         let equipment = Equipment::new();
         Player {
             name,
+            origin,
             fame: 0,
             fame_level: Fame::Novice,
-            energy: 100,
-            health: 100,
+            energy: max_energy(5),
+            health: max_health(5),
             strength: 5,
             agility: 5,
             defense: 1,
+            endurance: 5,
             money: 0,
-            max_health: 100,
             inventory,
             victories: 0,
-            injured: false,
+            injuries: Vec::new(),
             equipment,
+            relationships: Relationships::default(),
         }
     }
 
+    /// Changes fame without going below zero, promoting or demoting the player.
+    /// Returns the actual change.
+    pub fn change_fame(&mut self, change: i32) -> i32 {
+        let before = self.fame;
+        self.fame = (self.fame + change).max(0);
+        self.fame_level = Fame::from_points(self.fame);
+        self.fame - before
+    }
+
     pub fn take_damage(&mut self, amount: i32) {
-        // TODO: check for overflow for negative health
-        self.health -= amount;
+        self.health = (self.health - amount).max(0);
     }
 
-    pub fn heal(&mut self, amount: i32) {
-        self.health += amount;
-        if self.health >= self.max_health {
-            self.health = self.max_health;
+    pub fn attribute(&self, attribute: Attribute) -> u8 {
+        match attribute {
+            Attribute::Strength => self.strength,
+            Attribute::Agility => self.agility,
+            Attribute::Defense => self.defense,
+            Attribute::Endurance => self.endurance,
         }
     }
 
-    pub fn health_bar(&self) -> String {
-        let total_blocks = 10;
-        let health_percentage = self.health as f32 / self.max_health as f32;
-        let filled_blocks = (total_blocks as f32 * health_percentage).round() as i32;
-        let mut bar = String::new();
-        for _ in 0..filled_blocks {
-            bar.push('█');
+    pub fn raise(&mut self, attribute: Attribute, amount: u8) {
+        let (max_health, max_energy) = (self.max_health(), self.max_energy());
+        let value = match attribute {
+            Attribute::Strength => &mut self.strength,
+            Attribute::Agility => &mut self.agility,
+            Attribute::Defense => &mut self.defense,
+            Attribute::Endurance => &mut self.endurance,
+        };
+        *value = value.saturating_add(amount);
+        // Health and energy gained from endurance come fresh
+        self.health += self.max_health() - max_health;
+        self.change_energy(self.max_energy() as i32 - max_energy as i32);
+    }
+
+    /// Serious injuries keep the player from training and fighting.
+    pub fn is_injured(&self) -> bool {
+        self.injuries.iter().any(Injury::is_disabling)
+    }
+
+    pub fn can_wield_two_handed(&self) -> bool {
+        !self.injuries.iter().any(Injury::blocks_two_handed)
+    }
+
+    /// Attribute points lost to all injuries together.
+    pub fn penalties(&self) -> Penalties {
+        self.injuries.iter().map(Injury::penalties).fold(
+            Penalties::default(),
+            |total, penalties| Penalties {
+                strength: total.strength.saturating_add(penalties.strength),
+                agility: total.agility.saturating_add(penalties.agility),
+                defense: total.defense.saturating_add(penalties.defense),
+            },
+        )
+    }
+
+    /// Heals every injury by some weeks and returns the ones that are gone.
+    pub fn heal_injuries(&mut self, weeks: u32) -> Vec<Injury> {
+        let (healed, injuries) = std::mem::take(&mut self.injuries)
+            .into_iter()
+            .map(|mut injury| (injury.heal(weeks), injury))
+            .partition::<Vec<_>, _>(|(healed, _)| *healed);
+        self.injuries = injuries.into_iter().map(|(_, injury)| injury).collect();
+        healed.into_iter().map(|(_, injury)| injury).collect()
+    }
+
+    pub fn combat_stats(&self) -> CombatStats {
+        let penalties = self.penalties();
+        let mut stats = CombatStats::new(
+            self.strength.saturating_sub(penalties.strength),
+            self.agility.saturating_sub(penalties.agility),
+            self.defense.saturating_sub(penalties.defense) as u32,
+        );
+        stats.coverage = self.equipment.armor.coverage();
+        // A two-handed weapon is no use with a broken arm, that leaves the fists
+        if let Some(weapon) = self.equipment.weapon().filter(|weapon| {
+            weapon.item_type == HandItemType::Single || self.can_wield_two_handed()
+        }) {
+            stats.min_damage = weapon.durability.apply(weapon.min_damage);
+            stats.max_damage = weapon.durability.apply(weapon.max_damage);
         }
-        for _ in filled_blocks..total_blocks {
-            bar.push('░');
+        if let Some(shield) = self.equipment.shield() {
+            stats.block = shield.durability.apply(shield.block_damage);
         }
-        format!("Health: [{}] {}/{}", bar, self.health, self.max_health)
+        stats.weight = self.equipment.weight();
+        stats.tired(self.tiredness())
+    }
+
+    /// Endurance makes for a tougher gladiator.
+    pub fn max_health(&self) -> i32 {
+        max_health(self.endurance)
+    }
+
+    pub fn max_energy(&self) -> u8 {
+        max_energy(self.endurance)
     }
 
-    pub fn player_info(&self) {
-        print_line();
+    /// Tiredness on the same scale as the enemy's, the opposite of energy.
+    pub fn tiredness(&self) -> u8 {
+        let spent = self.max_energy().saturating_sub(self.energy);
+        tiredness_from(spent as i32, self.max_energy()) as u8
+    }
 
-        let mut table = Table::new();
+    /// Changes energy within its bounds and returns the actual change.
+    pub fn change_energy(&mut self, change: i32) -> i32 {
+        let before = self.energy;
+        self.energy = (self.energy as i32 + change).clamp(0, self.max_energy() as i32) as u8;
+        self.energy as i32 - before as i32
+    }
 
-        table.set_titles(Row::new(vec![Cell::new("Attribute"), Cell::new("Value")]));
-        table.add_row(row!["Money", format!("💰 {}", self.money)]);
-        table.add_row(row!["Fame", format!("🏆 {:?}", self.fame_level)]); // TODO: implement display for Fame
-        table.add_row(row!["Energy", format!("⚡ {}", self.energy)]);
-        table.add_row(row!["Victories", format!("⚔️  {}", self.victories)]);
-        table.add_row(row![
-            "Health",
-            format!("❤️ {}/{}", self.health, self.max_health)
-        ]);
-        table.add_row(row!["Strength", format!("💪 {}", self.strength)]);
-        table.add_row(row!["Defense", format!("🛡️ {}", self.defense)]);
-        table.add_row(row!["Agility", format!("🏃 {}", self.agility)]);
+    /// Fighting costs energy the same way it tires the enemy.
+    pub fn exert(&mut self, action: FightAction) {
+        self.change_energy(-action.exertion());
+    }
 
-        println!("👤 Player Information: {}", self.name);
-        table.printstd();
+    pub fn heal(&mut self, amount: i32) {
+        self.health += amount;
+        if self.health >= self.max_health() {
+            self.health = self.max_health();
+        }
+    }
+
+    pub fn health_bar(&self) -> String {
+        health_bar(self.health, self.max_health())
+    }
+
+    pub fn info_rows(&self) -> Table {
+        let injuries = if self.injuries.is_empty() {
+            "None".to_string()
+        } else {
+            self.injuries
+                .iter()
+                .map(Injury::to_string)
+                .collect::<Vec<_>>()
+                .join("; ")
+        };
+        let mut table = Table::new(&["Attribute", "Value"]);
+        for (attribute, value) in [
+            ("Origin", self.origin.to_string()),
+            ("Money", format!("💰 {}", self.money)),
+            ("Fame", format!("🏆 {} ({})", self.fame_level, self.fame)),
+            (
+                "Energy",
+                format!("⚡ {}/{}", self.energy, self.max_energy()),
+            ),
+            ("Victories", format!("⚔️  {}", self.victories)),
+            (
+                "Health",
+                format!("❤️ {}/{}", self.health, self.max_health()),
+            ),
+            ("Strength", format!("💪 {}", self.strength)),
+            ("Defense", format!("🛡️ {}", self.defense)),
+            ("Agility", format!("🏃 {}", self.agility)),
+            ("Endurance", format!("🫀 {}", self.endurance)),
+            ("Injuries", format!("🩹 {}", injuries)),
+            ("Lanista's Favor", self.relationships.lanista.to_string()),
+            (
+                "Gladiators' Respect",
+                self.relationships.gladiators.to_string(),
+            ),
+        ] {
+            table.rows.push(vec![attribute.to_string(), value]);
+        }
+        table
     }
 }
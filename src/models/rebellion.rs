@@ -0,0 +1,100 @@
+use super::character::Attribute;
+use super::player::{Fame, Player};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+// Only a famous, strong gladiator who has served a while can lead a revolt
+const REQUIRED_FAME: Fame = Fame::Veteran;
+const REQUIRED_ATTRIBUTES: u32 = 36;
+const REQUIRED_WEEKS: u64 = 24;
+pub const RECRUITS_NEEDED: u8 = 4;
+pub const PREPARATION_NEEDED: u8 = 100;
+// Suspicion raised by a week of plotting, a refusal can loosen tongues
+const RECRUIT_SUSPICION: u8 = 5;
+const REFUSAL_SUSPICION: u8 = 15;
+const PREPARATION_SUSPICION: u8 = 10;
+const MIN_DISCOVERY_CHANCE: f64 = 0.02;
+const MAX_DISCOVERY_CHANCE: f64 = 0.6;
+
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum Stage {
+    /// Nobody in the ludus would follow the player yet.
+    #[default]
+    Dormant,
+    Recruiting,
+    /// Enough gladiators are in, weapons and keys have to be gathered.
+    Preparing,
+    Ready,
+}
+
+/// The plot to break out of the ludus, from the first whispers to the night of the revolt.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Rebellion {
+    pub stage: Stage,
+    pub recruits: u8,
+    pub preparation: u8,
+    pub suspicion: u8,
+}
+
+impl Rebellion {
+    /// Whether the other gladiators would listen to the player's plans.
+    pub fn can_start(player: &Player, weeks_served: u64) -> bool {
+        let attributes: u32 = Attribute::ALL
+            .iter()
+            .map(|attribute| player.attribute(*attribute) as u32)
+            .sum();
+        player.fame_level >= REQUIRED_FAME
+            && attributes >= REQUIRED_ATTRIBUTES
+            && weeks_served >= REQUIRED_WEEKS
+    }
+
+    /// Tries to win over another gladiator. Those who respect the player
+    /// are easier to convince, and every refusal is a risk.
+    pub fn recruit<R: Rng>(&mut self, rng: &mut R, gladiators_respect: i32) -> bool {
+        let chance = (0.4 + gladiators_respect as f64 / 100.0).clamp(0.1, 0.9);
+        let recruited = rng.gen_bool(chance);
+        if recruited {
+            self.recruits += 1;
+            self.raise_suspicion(RECRUIT_SUSPICION);
+            if self.recruits >= RECRUITS_NEEDED {
+                self.stage = Stage::Preparing;
+            }
+        } else {
+            self.raise_suspicion(REFUSAL_SUSPICION);
+        }
+        recruited
+    }
+
+    /// A week of stealing weapons and bribing guards, returns the progress made.
+    pub fn prepare<R: Rng>(&mut self, rng: &mut R) -> u8 {
+        let progress = rng.gen_range(20, 41);
+        self.preparation = (self.preparation + progress).min(PREPARATION_NEEDED);
+        self.raise_suspicion(PREPARATION_SUSPICION);
+        if self.preparation >= PREPARATION_NEEDED {
+            self.stage = Stage::Ready;
+        }
+        progress
+    }
+
+    /// Chance the lanista learns of the plot this week. A lanista who
+    /// favors the player is slower to believe the rumours.
+    pub fn discovery_chance(&self, lanista_favor: i32) -> f64 {
+        (self.suspicion as f64 / 100.0 - lanista_favor as f64 / 200.0)
+            .clamp(MIN_DISCOVERY_CHANCE, MAX_DISCOVERY_CHANCE)
+    }
+
+    /// The plot is uncovered: the recruits are sold off and everything
+    /// has to start again. Returns the number of recruits lost.
+    pub fn discovered(&mut self) -> u8 {
+        let recruits = self.recruits;
+        *self = Rebellion {
+            stage: Stage::Recruiting,
+            ..Rebellion::default()
+        };
+        recruits
+    }
+
+    fn raise_suspicion(&mut self, amount: u8) {
+        self.suspicion = self.suspicion.saturating_add(amount).min(100);
+    }
+}
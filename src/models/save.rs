@@ -0,0 +1,294 @@
+use super::game::Game;
+use super::migration::{migrate, SAVE_VERSION};
+use chrono::{Local, TimeZone};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::{fmt, fs, io};
+
+pub const SAVE_SLOTS: usize = 3;
+// Written automatically at week boundaries and after fights
+pub const AUTOSAVE_SLOT: usize = 0;
+
+/// Summary of a save shown in the load and save menus without
+/// having to read the whole game.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SaveMetadata {
+    pub name: String,
+    pub player_name: String,
+    pub week: u64,
+    pub victories: i32,
+    pub money: i32,
+    pub saved_at: i64, // unix timestamp
+}
+
+impl SaveMetadata {
+    pub fn new(name: String, game: &Game) -> Self {
+        let player = game.player();
+        SaveMetadata {
+            name,
+            player_name: player.name.clone(),
+            week: game.current_week(),
+            victories: player.victories,
+            money: player.money,
+            saved_at: Local::now().timestamp(),
+        }
+    }
+
+    pub fn saved_at(&self) -> String {
+        match Local.timestamp_opt(self.saved_at, 0).single() {
+            Some(time) => time.format("%Y-%m-%d %H:%M").to_string(),
+            None => "unknown".to_string(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SaveError {
+    Io(io::Error),
+    Corrupted(serde_json::Error),
+    NewerVersion { found: u32, supported: u32 },
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SaveError::Io(error) => write!(f, "Could not access the save file: {}", error),
+            SaveError::Corrupted(error) => write!(f, "The save file is corrupted: {}", error),
+            SaveError::NewerVersion { found, supported } => write!(
+                f,
+                "The save was made by a newer version of the game (format {}, this version reads up to {})",
+                found, supported
+            ),
+        }
+    }
+}
+
+impl From<io::Error> for SaveError {
+    fn from(error: io::Error) -> Self {
+        SaveError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for SaveError {
+    fn from(error: serde_json::Error) -> Self {
+        SaveError::Corrupted(error)
+    }
+}
+
+#[derive(Serialize)]
+struct SaveFileRef<'a> {
+    version: u32,
+    metadata: SaveMetadata,
+    game: &'a Game,
+}
+
+#[derive(Deserialize)]
+struct SaveFile {
+    game: Game,
+}
+
+#[derive(Deserialize)]
+struct SaveHeader {
+    metadata: SaveMetadata,
+}
+
+/// Numbered save slots stored as json files in a directory.
+/// Every write goes to a temporary file that is renamed over the slot,
+/// after linking the previous save as a `.bak` file next to it.
+pub struct Saves {
+    dir: PathBuf,
+}
+
+impl Saves {
+    pub fn new(dir: PathBuf) -> Self {
+        Saves { dir }
+    }
+
+    fn path(&self, slot: usize) -> PathBuf {
+        if slot == AUTOSAVE_SLOT {
+            self.dir.join("autosave.json")
+        } else {
+            self.dir.join(format!("save{}.json", slot))
+        }
+    }
+
+    fn backup_path(&self, slot: usize) -> PathBuf {
+        self.path(slot).with_extension("json.bak")
+    }
+
+    /// `None` for empty or unreadable slots.
+    pub fn metadata(&self, slot: usize) -> Option<SaveMetadata> {
+        read_metadata(&self.path(slot))
+    }
+
+    pub fn backup_metadata(&self, slot: usize) -> Option<SaveMetadata> {
+        read_metadata(&self.backup_path(slot))
+    }
+
+    pub fn is_used(&self, slot: usize) -> bool {
+        self.path(slot).exists()
+    }
+
+    pub fn has_backup(&self, slot: usize) -> bool {
+        self.backup_path(slot).exists()
+    }
+
+    pub fn save(&self, slot: usize, name: String, game: &Game) -> io::Result<()> {
+        let save = SaveFileRef {
+            version: SAVE_VERSION,
+            metadata: SaveMetadata::new(name, game),
+            game,
+        };
+        let json = serde_json::to_string(&save)?;
+        self.write_atomic(slot, json.as_bytes())
+    }
+
+    pub fn autosave(&self, game: &Game) -> io::Result<()> {
+        self.save(AUTOSAVE_SLOT, "Autosave".to_string(), game)
+    }
+
+    fn write_atomic(&self, slot: usize, contents: &[u8]) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let path = self.path(slot);
+        if path.exists() {
+            // The slot itself stays in place until the new save replaces it
+            let backup_path = self.backup_path(slot);
+            if backup_path.exists() {
+                fs::remove_file(&backup_path)?;
+            }
+            if fs::hard_link(&path, &backup_path).is_err() {
+                fs::copy(&path, &backup_path)?;
+            }
+        }
+        self.replace(&path, contents)
+    }
+
+    /// Writes `contents` to a temporary file and renames it over `path`,
+    /// so a crash leaves either the old file or the new one.
+    fn replace(&self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        let temp_path = path.with_extension("json.tmp");
+        let mut file = File::create(&temp_path)?;
+        file.write_all(contents)?;
+        file.sync_all()?;
+        fs::rename(&temp_path, path)?;
+        self.sync_dir()
+    }
+
+    /// Makes the renames in the save directory durable.
+    #[cfg(unix)]
+    fn sync_dir(&self) -> io::Result<()> {
+        File::open(&self.dir)?.sync_all()
+    }
+
+    #[cfg(not(unix))]
+    fn sync_dir(&self) -> io::Result<()> {
+        Ok(())
+    }
+
+    pub fn load(&self, slot: usize) -> Result<Game, SaveError> {
+        load_game(&self.path(slot))
+    }
+
+    /// Loads the previous save of a slot and puts it back in place.
+    pub fn restore_backup(&self, slot: usize) -> Result<Game, SaveError> {
+        let game = load_game(&self.backup_path(slot))?;
+        let contents = fs::read(self.backup_path(slot))?;
+        fs::create_dir_all(&self.dir)?;
+        self.replace(&self.path(slot), &contents)?;
+        Ok(game)
+    }
+
+    pub fn delete(&self, slot: usize) -> io::Result<()> {
+        if self.has_backup(slot) {
+            fs::remove_file(self.backup_path(slot))?;
+        }
+        fs::remove_file(self.path(slot))
+    }
+}
+
+/// Reads a save file and upgrades it to the current save format.
+fn read(path: &Path) -> Result<Value, SaveError> {
+    let contents = fs::read_to_string(path)?;
+    let document = serde_json::from_str(&contents)?;
+    migrate(document).map_err(|found| SaveError::NewerVersion {
+        found,
+        supported: SAVE_VERSION,
+    })
+}
+
+fn read_metadata(path: &Path) -> Option<SaveMetadata> {
+    let header: SaveHeader = serde_json::from_value(read(path).ok()?).ok()?;
+    Some(header.metadata)
+}
+
+/// Loads a save file from anywhere, not only from a slot.
+pub fn load_game(path: &Path) -> Result<Game, SaveError> {
+    let save: SaveFile = serde_json::from_value(read(path)?)?;
+    Ok(save.game)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::character::Character;
+    use crate::models::command::Command;
+    use std::process;
+
+    /// An empty directory of its own for every test.
+    fn saves(test: &str) -> Saves {
+        let dir = std::env::temp_dir().join(format!("terminal_arena_{}_{}", test, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        Saves::new(dir)
+    }
+
+    fn game(weeks: usize) -> Game {
+        let mut game = Game::with_seed(&Character::new("Spartacus".to_string()), 1);
+        for _ in 0..weeks {
+            game.execute(Command::SkipFight);
+        }
+        game
+    }
+
+    #[test]
+    fn saving_again_keeps_the_previous_save_as_backup() {
+        let saves = saves("backup");
+        saves.save(1, "First".to_string(), &game(0)).unwrap();
+        assert!(!saves.has_backup(1));
+
+        saves.save(1, "Second".to_string(), &game(1)).unwrap();
+        assert_eq!(saves.metadata(1).unwrap().name, "Second");
+        assert_eq!(saves.backup_metadata(1).unwrap().name, "First");
+        assert!(!saves.path(1).with_extension("json.tmp").exists());
+        fs::remove_dir_all(&saves.dir).unwrap();
+    }
+
+    #[test]
+    fn restoring_the_backup_puts_the_older_game_back() {
+        let saves = saves("restore");
+        saves.save(1, "First".to_string(), &game(0)).unwrap();
+        saves.save(1, "Second".to_string(), &game(1)).unwrap();
+        assert_eq!(saves.load(1).unwrap().current_week(), 1);
+
+        let restored = saves.restore_backup(1).unwrap();
+        assert_eq!(restored.current_week(), 0);
+        assert_eq!(saves.load(1).unwrap().current_week(), 0);
+        assert_eq!(saves.metadata(1).unwrap().name, "First");
+        assert!(!saves.path(1).with_extension("json.tmp").exists());
+        fs::remove_dir_all(&saves.dir).unwrap();
+    }
+
+    #[test]
+    fn deleting_a_slot_removes_its_backup() {
+        let saves = saves("delete");
+        saves.save(1, "First".to_string(), &game(0)).unwrap();
+        saves.save(1, "Second".to_string(), &game(1)).unwrap();
+
+        saves.delete(1).unwrap();
+        assert!(!saves.is_used(1));
+        assert!(!saves.has_backup(1));
+        fs::remove_dir_all(&saves.dir).unwrap();
+    }
+}
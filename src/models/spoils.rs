@@ -0,0 +1,71 @@
+use super::enemy::Enemy;
+use super::items::item::{Equipable, Item};
+use super::items::loot;
+use super::utils::Table;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+// The lanista takes this share of the spoils, in percent...
+const LANISTA_CUT: i32 = 50;
+// ...less from a favored gladiator, within these bounds
+const MIN_LANISTA_CUT: i32 = 20;
+const MAX_LANISTA_CUT: i32 = 80;
+// Gear taken off a beaten opponent has seen some fighting
+const MIN_WEAR: u8 = 20;
+const MAX_WEAR: u8 = 60;
+
+/// What is left of a beaten opponent's gear once the fight is over.
+/// The player picks what to keep, the lanista sells off the rest.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Spoils {
+    pub items: Vec<Item>,
+    /// Items the player may still claim.
+    pub claims: usize,
+}
+
+impl Spoils {
+    /// Share of the spoils the lanista keeps, in percent.
+    pub fn lanista_cut(lanista_favor: i32) -> i32 {
+        (LANISTA_CUT - lanista_favor / 2).clamp(MIN_LANISTA_CUT, MAX_LANISTA_CUT)
+    }
+
+    /// Famous opponents fight with finer gear, and leave finer spoils.
+    pub fn new<R: Rng>(rng: &mut R, enemy: &Enemy, cut: i32) -> Self {
+        let items: Vec<Item> = enemy
+            .gear()
+            .into_iter()
+            .map(|item| {
+                let mut item = loot::refine(rng, item, enemy.fame);
+                item.durability_mut()
+                    .wear(rng.gen_range(MIN_WEAR, MAX_WEAR + 1));
+                item
+            })
+            .collect();
+        // Rounded up, whatever the cut the player gets something
+        let claims = (items.len() * (100 - cut) as usize).div_ceil(100);
+        Spoils { items, claims }
+    }
+
+    pub fn claim(&mut self, index: usize) -> Option<Item> {
+        if self.claims == 0 || index >= self.items.len() {
+            return None;
+        }
+        self.claims -= 1;
+        Some(self.items.remove(index))
+    }
+
+    pub fn table(&self) -> Table {
+        let mut table = Table::new(&["Name", "Rarity", "Slot", "Stats", "Durability", "Value"]);
+        for item in &self.items {
+            table.rows.push(vec![
+                item.name().to_string(),
+                item.rarity().to_string(),
+                item.slot().to_string(),
+                item.stats().to_string(),
+                item.durability().to_string(),
+                item.price().to_string(),
+            ]);
+        }
+        table
+    }
+}
@@ -1,153 +1,72 @@
-use crate::models::items::shield;
-use crate::models::utils::print_line;
-
-use super::items::hand_item::{HandItem, HandItemType};
-use super::items::shield::Shield;
-use super::items::{armor::Armor, weapon::Weapon};
-use prettytable::{cell, row, Table};
+use super::inventory::Inventory;
+use super::items::catalog;
+use super::items::durability::MAX_DURABILITY;
+use super::items::hand_item::Equipment;
+use super::items::item::{Equipable, Item, Slot};
+use super::items::loot::{self, Rarity};
+use super::player::Fame;
+use super::utils::Table;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
+// A full repair costs this share of the item's price, in percent
+const REPAIR_COST: i32 = 50;
+// Rolled items on sale next to the regular catalog
+const LOOT_STOCK: usize = 6;
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Store {
-    pub weapons: Vec<Weapon>,
-    pub shields: Vec<Shield>,
-    pub helmets: Vec<Armor>,
+    pub items: Inventory,
 }
 
 impl Store {
-    pub fn new() -> Self {
-        let weapons: Vec<Weapon> = vec![
-            Weapon::new("Rusty Dagger".to_string(), HandItemType::Single, 2, 5, 5, 5),
-            Weapon::new(
-                "Wooden Club".to_string(),
-                HandItemType::Single,
-                4,
-                8,
-                10,
-                200,
-            ),
-            Weapon::new(
-                "Short Sword".to_string(),
-                HandItemType::Single,
-                6,
-                10,
-                15,
-                350,
-            ),
-            Weapon::new(
-                "Battle Axe".to_string(),
-                HandItemType::Single,
-                8,
-                15,
-                20,
-                500,
-            ),
-            Weapon::new(
-                "War Hammer".to_string(),
-                HandItemType::Double,
-                10,
-                18,
-                25,
-                700,
-            ),
-            Weapon::new(
-                "Longsword".to_string(),
-                HandItemType::Single,
-                12,
-                20,
-                30,
-                900,
-            ),
-            Weapon::new("Flail".to_string(), HandItemType::Single, 14, 22, 35, 1100),
-            Weapon::new(
-                "Greatsword".to_string(),
-                HandItemType::Double,
-                16,
-                25,
-                40,
-                1350,
-            ),
-            Weapon::new(
-                "Halberd".to_string(),
-                HandItemType::Double,
-                18,
-                28,
-                45,
-                1600,
-            ),
-            Weapon::new(
-                "Mystic Staff".to_string(),
-                HandItemType::Single,
-                20,
-                30,
-                50,
-                2000,
-            ),
-        ];
-
+    pub fn new<R: Rng>(rng: &mut R) -> Self {
+        let weapons = catalog::weapons().into_iter().map(Item::Weapon);
+        let shields = catalog::shields().into_iter().map(Item::Shield);
+        let armors = catalog::armors().into_iter().map(Item::Armor);
+        let loot = Store::roll_loot(rng);
         Store {
-            weapons,
-            shields: Vec::new(),
-            helmets: Vec::new(),
+            items: Inventory::with_items(
+                weapons.chain(shields).chain(armors).chain(loot).collect(),
+            ),
         }
     }
 
-    pub fn add_weapon(&mut self, item: Weapon) {
-        self.weapons.push(item);
+    /// Rolled items worth stocking, common ones are in the catalog already.
+    pub fn roll_loot<R: Rng>(rng: &mut R) -> Vec<Item> {
+        std::iter::repeat_with(|| loot::roll(rng, 0))
+            .filter(|item| item.rarity() > Rarity::Common)
+            .take(LOOT_STOCK)
+            .collect()
     }
 
-    pub fn remove_weapon(&mut self, index: usize) {
-        self.weapons.remove(index);
+    /// Prices are what a gladiator of the given fame pays.
+    pub fn table(&self, slot: Slot, fame: Fame) -> Table {
+        self.items.priced_table(slot, |price| fame.price(price))
     }
 
-    pub fn print_all_weapons(&self) {
-        let mut weapons_table = Table::new();
-
-        weapons_table.set_titles(row!["Name", "Type", "Damage", "Required Strength", "Price"]);
-
-        for weapon in &self.weapons {
-            weapons_table.add_row(row![
-                &weapon.name,
-                &weapon.item_type,
-                format!("{}-{}", weapon.min_damage, weapon.max_damage),
-                weapon.req_strength.to_string(),
-                weapon.price
-            ]);
+    /// What the blacksmith asks to mend an item, the more worn the dearer.
+    pub fn repair_price(&self, item: &dyn Equipable) -> i32 {
+        let missing = item.durability().missing() as i32;
+        if missing == 0 {
+            return 0;
         }
-
-        // Print the tablesß
-        println!("Store");
-        print_line();
-        println!("Weapons");
-        weapons_table.printstd();
+        (item.price() * REPAIR_COST * missing / (100 * MAX_DURABILITY as i32)).max(1)
     }
 
-    pub fn print_all_shields(&self) {
-        let mut shields_table = Table::new();
-
-        shields_table.set_titles(row![
-            "Name",
-            "Type",
-            "Block Damage",
-            "Weight",
-            "Required Strength",
-            "Price"
-        ]);
-
-        for shield in &self.shields {
-            shields_table.add_row(row![
-                &shield.name,
-                &shield.item_type,
-                shield.block_damage,
-                shield.weight,
-                shield.req_strength,
-                shield.price
-            ]);
+    /// The blacksmith's offer for the gear the player wears.
+    pub fn repairs_table(&self, equipment: &Equipment) -> Table {
+        let mut table = Table::new(&["Slot", "Item", "Durability", "Repair Price"]);
+        for slot in Slot::ALL {
+            if let Some(item) = equipment.get(slot) {
+                table.rows.push(vec![
+                    slot.to_string(),
+                    item.name().to_string(),
+                    item.durability().to_string(),
+                    self.repair_price(item).to_string(),
+                ]);
+            }
         }
-        // Print the tables
-        println!("Store");
-        print_line();
-        println!("Shields");
-        shields_table.printstd();
+        table
     }
 }
@@ -0,0 +1,68 @@
+use super::character::Attribute;
+use super::combat::MAX_TIREDNESS;
+use rand::Rng;
+use std::fmt;
+
+/// Coins the doctore asks for a week of personal training.
+pub const DOCTORE_PRICE: i32 = 50;
+
+// Chance of progress is this minus the stat over STAT_DIVISOR, so gains slow down
+const BASE_PROGRESS: f64 = 1.5;
+const STAT_DIVISOR: f64 = 15.0;
+const MIN_PROGRESS: f64 = 0.1;
+// Training injuries happen this often to a fresh gladiator...
+const INJURY_CHANCE: f64 = 0.05;
+// ...and this much more often to an exhausted one
+const EXHAUSTION_RISK: f64 = 0.1;
+
+/// A week of drills for one attribute, alone or under the doctore's eye.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Regimen {
+    pub attribute: Attribute,
+    pub doctore: bool,
+}
+
+impl fmt::Display for Regimen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.doctore {
+            write!(f, "{} drills with the doctore", self.attribute)
+        } else {
+            write!(f, "{} drills", self.attribute)
+        }
+    }
+}
+
+impl Regimen {
+    /// Chance that a week of drills raises an attribute at `value`.
+    /// Exhausted gladiators get half as much out of it.
+    pub fn progress_chance(&self, value: u8, exhausted: bool) -> f64 {
+        let mut chance = (BASE_PROGRESS - value as f64 / STAT_DIVISOR).clamp(MIN_PROGRESS, 1.0);
+        if exhausted {
+            chance /= 2.0;
+        }
+        chance
+    }
+
+    /// Points gained this week. The doctore pushes for a second attempt.
+    pub fn roll_gain<R: Rng>(&self, rng: &mut R, value: u8, exhausted: bool) -> u8 {
+        let attempts = if self.doctore { 2 } else { 1 };
+        let mut gain = 0;
+        for _ in 0..attempts {
+            if rng.gen_bool(self.progress_chance(value.saturating_add(gain), exhausted)) {
+                gain += 1;
+            }
+        }
+        gain
+    }
+
+    /// Whether the gladiator gets hurt during the drills. The doctore
+    /// keeps an eye on form and halves the risk.
+    pub fn roll_injury<R: Rng>(&self, rng: &mut R, tiredness: u8) -> bool {
+        let mut chance = INJURY_CHANCE
+            + tiredness.min(MAX_TIREDNESS) as f64 / MAX_TIREDNESS as f64 * EXHAUSTION_RISK;
+        if self.doctore {
+            chance /= 2.0;
+        }
+        rng.gen_bool(chance)
+    }
+}
@@ -1,4 +1,5 @@
 use std::io::{stdout, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
 use std::{io, thread};
 
@@ -9,11 +10,26 @@ use crossterm::event::{self, Event, KeyCode};
 use crossterm::terminal::{disable_raw_mode, enable_raw_mode, size};
 use crossterm::QueueableCommand;
 
+/// Delay between characters typed by [`slow_type`].
+pub const DEFAULT_TYPING_DELAY_MS: u64 = 25;
+
+static TYPING_DELAY_MS: AtomicU64 = AtomicU64::new(DEFAULT_TYPING_DELAY_MS);
+
+/// A delay of 0 prints text at once.
+pub fn set_typing_delay(millis: u64) {
+    TYPING_DELAY_MS.store(millis, Ordering::Relaxed);
+}
+
 pub fn slow_type(text: &str) {
     let mut stdout = io::stdout();
     let mut chars_printed = 0;
+    let delay = TYPING_DELAY_MS.load(Ordering::Relaxed);
+    if delay == 0 {
+        println!("{}", text);
+        return;
+    }
     // Enable raw mode
-    if let Ok(_) = enable_raw_mode() {
+    if enable_raw_mode().is_ok() {
         for c in text.chars() {
             // Check for space bar press to print the rest of the string immediately
             if event::poll(Duration::from_millis(0)).unwrap() {
@@ -30,7 +46,7 @@ pub fn slow_type(text: &str) {
             print!("{}", c);
             stdout.flush().unwrap();
             chars_printed += c.len_utf8();
-            thread::sleep(Duration::from_millis(25));
+            thread::sleep(Duration::from_millis(delay));
         }
 
         // Move to the next line after finishing printing
@@ -44,6 +60,25 @@ pub fn slow_type(text: &str) {
     }
 }
 
+pub fn health_bar(health: i32, max_health: i32) -> String {
+    format!("Health: {}", meter(health, max_health))
+}
+
+/// A ten block bar followed by the value, e.g. `[█████░░░░░] 50/100`.
+pub fn meter(value: i32, max: i32) -> String {
+    let total_blocks = 10;
+    let filled = value as f32 / max as f32;
+    let filled_blocks = (total_blocks as f32 * filled).round() as i32;
+    let mut bar = String::new();
+    for _ in 0..filled_blocks {
+        bar.push('█');
+    }
+    for _ in filled_blocks..total_blocks {
+        bar.push('░');
+    }
+    format!("[{}] {}/{}", bar, value, max)
+}
+
 pub fn print_line() {
     let (cols, _) = size().unwrap(); // Get the size of the terminal (columns, rows)
     println!("{}", "═".repeat(cols as usize)); // Print a line that matches the width of the terminal
@@ -80,3 +115,19 @@ fn print_centered_art(art: &str) -> Result<(), Error> {
 
     Ok(())
 }
+
+/// Titled rows of text, laid out by the frontend.
+#[derive(Debug, Default, Clone)]
+pub struct Table {
+    pub titles: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(titles: &[&str]) -> Self {
+        Table {
+            titles: titles.iter().map(|title| title.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+}